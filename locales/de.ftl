@@ -0,0 +1,405 @@
+## Roles
+
+role-admin = Administrator
+role-user = Benutzer
+role-none = Keine Rolle
+
+## Password policy
+
+password-min-length = Mindestens { $count } Zeichen verwenden
+password-add-lowercase = Einen Kleinbuchstaben hinzufügen
+password-add-uppercase = Einen Großbuchstaben hinzufügen
+password-add-digit = Eine Ziffer hinzufügen
+password-add-symbol = Ein Sonderzeichen hinzufügen
+password-no-username = Den Benutzernamen nicht verwenden
+
+## Live updates
+
+live-transport-sse = Server-Sent Events
+live-transport-websocket = WebSocket
+live-state-off = Aus
+live-state-connecting = Verbinde
+live-state-connected = Verbunden
+live-state-polling = Getrennt, frage regelmäßig ab
+
+## Commands
+
+command-open-palette = Befehlspalette
+command-create-movie = Film anlegen
+command-list-movies = Filme laden
+command-update-movie = Film bearbeiten
+command-delete-movie = Film löschen
+command-clear-selection = Auswahl aufheben
+command-select-previous = Vorherigen Film auswählen
+command-select-next = Nächsten Film auswählen
+command-focus-search = Filme durchsuchen
+command-register = Registrieren
+command-login = Anmelden
+command-logout = Abmelden
+command-change-password = Passwort ändern
+command-delete-account = Konto löschen
+command-manage-users = Benutzer verwalten
+command-proxy-settings = Proxy-Einstellungen
+command-live-updates = Live-Update-Einstellungen
+command-appearance = Darstellungseinstellungen
+command-toggle-theme = Zwischen hellem und dunklem Design wechseln
+command-keyboard-shortcuts = Tastenkürzel
+command-toggle-notifications = Benachrichtigungen ein-/ausblenden
+command-reset-layout = Layout zurücksetzen
+
+## Appearance
+
+language-system = Systemsprache
+theme-light = Hell
+theme-dark = Dunkel
+theme-system = Systemeinstellung
+accent-default = Standard
+accent-blue = Blau
+accent-green = Grün
+accent-purple = Lila
+accent-orange = Orange
+accent-pink = Pink
+
+## TLS
+
+tls-system-trust = System-Zertifikate
+tls-extra-cas = { $count ->
+        [one] { $count } zusätzliche CA
+       *[other] { $count } zusätzliche CAs
+    }
+tls-client-cert = Client-Zertifikat ({ $format })
+tls-min-version = mind. TLS { $version }
+
+## Errors
+
+error-create-client = Client konnte nicht erstellt werden: { $error }
+error-read-ca-file = CA-Datei { $path } konnte nicht gelesen werden: { $error }
+error-read-client-cert = Client-Zertifikat { $path } konnte nicht gelesen werden: { $error }
+error-read-client-key = Client-Schlüssel { $path } konnte nicht gelesen werden: { $error }
+error-read-file = { $path } konnte nicht gelesen werden: { $error }
+error-no-base-url = Keine Basis-URL angegeben
+error-unauthorized = Nicht autorisiert
+error-unsupported-live-url = Nicht unterstützte Live-Update-URL { $url }
+error-no-credentials = Keine Anmeldedaten angegeben
+error-no-role = Keine Rolle angegeben
+error-no-password = Kein Passwort angegeben
+error-no-session-cookie = Kein Sitzungs-Cookie erhalten
+error-register-user = Benutzer konnte nicht registriert werden
+error-log-in = Anmeldung fehlgeschlagen
+error-log-out = Abmeldung fehlgeschlagen
+error-fetch-identity = Benutzeridentität konnte nicht abgerufen werden
+error-create-movie = Film konnte nicht erstellt werden
+error-fetch-movie = Film konnte nicht abgerufen werden
+error-fetch-movies = Filme konnten nicht abgerufen werden
+error-update-movie = Film konnte nicht aktualisiert werden
+error-delete-movie = Film konnte nicht gelöscht werden
+error-no-movie-found = Kein Film gefunden
+error-no-movies-found = Keine Filme gefunden
+error-upload-poster = Poster konnte nicht hochgeladen werden
+error-poster-too-large = Posterdateien dürfen höchstens { $limit } MB groß sein
+error-poster-not-image = { $path } ist kein PNG-, JPEG-, GIF- oder WebP-Bild
+error-load-poster = Poster konnte nicht geladen werden: { $status }
+error-fetch-users = Benutzer konnten nicht abgerufen werden
+error-parse-users = Benutzer konnten nicht gelesen werden: { $error }
+error-change-role = Rolle konnte nicht geändert werden
+error-reset-password = Passwort konnte nicht zurückgesetzt werden
+error-change-user-state = Benutzerstatus konnte nicht geändert werden
+error-delete-user = Benutzer konnte nicht gelöscht werden
+error-change-password = Passwort konnte nicht geändert werden
+error-delete-account = Konto konnte nicht gelöscht werden
+error-no-username = Kein Benutzername in der Identität
+error-not-jwt = Token ist kein JWT
+error-event-stream-status = Ereignisstrom lieferte { $status }
+error-connection-closed = Verbindung vom Server geschlossen
+error-stream-closed = Strom geschlossen
+error-stream-closed-by-server = Strom vom Server geschlossen
+error-authorization-listener-stopped = Autorisierungs-Listener wurde beendet
+error-fetch-discovery = OIDC-Discovery-Dokument konnte nicht abgerufen werden
+error-no-authorization-url = Keine Autorisierungs-URL angegeben
+error-no-token-url = Keine Token-URL angegeben
+error-obtain-token = Token konnte nicht abgerufen werden
+error-authorization-timeout = Zeitüberschreitung beim Warten auf die Autorisierung
+error-authorization-failed = Autorisierung fehlgeschlagen: { $error }
+error-authorization-state-mismatch = Autorisierungsstatus stimmt nicht überein
+error-no-authorization-code = Kein Autorisierungscode erhalten
+oauth-login-complete = Anmeldung abgeschlossen. Sie können dieses Fenster schließen.
+oauth-login-failed = Anmeldung fehlgeschlagen. Sie können dieses Fenster schließen.
+
+## Search
+
+search-missing-quote = Schließendes Anführungszeichen fehlt
+search-invalid-year = Ungültiges Jahr „{ $value }“
+search-invalid-id = Ungültige ID „{ $value }“
+search-unknown-field = Unbekanntes Feld „{ $name }“
+search-missing-value = Fehlender Wert für „{ $name }“
+
+## Common
+
+button-save = Speichern
+button-cancel = Abbrechen
+button-reset = Zurücksetzen
+
+## Command palette and shortcuts
+
+palette-hint = Befehl eingeben
+palette-no-matches = Keine passenden Befehle
+shortcuts-hint = Namen wie Ctrl+Shift+P, F5 oder ArrowDown verwenden, leer lassen zum Entfernen
+shortcut-unknown-modifier = Unbekannte Zusatztaste „{ $modifier }“
+shortcut-unknown-key = Unbekannte Taste „{ $key }“
+shortcut-already-used = { $shortcut } wird bereits von { $command } verwendet
+
+## Appearance dialog
+
+appearance-title = Darstellung
+appearance-theme = Design:
+appearance-accent = Akzentfarbe:
+appearance-ui-scale = Skalierung:
+appearance-ui-scale-hint = Strg + Plus und Strg + Minus ändern die Skalierung ebenfalls
+
+## Dialogs
+
+button-confirm = Bestätigen
+label-username = Benutzername:
+label-password = Passwort:
+validation-name-required = Name ist erforderlich
+validation-username-required = Benutzername ist erforderlich
+validation-password-required = Passwort ist erforderlich
+password-show = Anzeigen
+password-hide = Verbergen
+password-caps-lock = Feststelltaste ist aktiv
+password-weak = Schwach
+password-fair = Mittel
+password-good = Gut
+password-strong = Stark
+save-search-title = Suche speichern
+save-search-query = Abfrage: { $query }
+save-search-name = Name:
+save-search-replace = Eine gespeicherte Suche mit diesem Namen wird ersetzt
+login-title = Anmelden
+login-intro = Bei Ihrem Konto anmelden
+login-oauth-intro = Mit dem konfigurierten Autorisierungsserver anmelden
+login-button = Anmelden
+
+## Notifications
+
+severity-info = Info
+severity-success = Erfolg
+severity-warning = Warnung
+severity-error = Fehler
+action-log-in-again = Erneut anmelden
+action-retry = Wiederholen
+action-undo = Rückgängig
+notifications-title = Benachrichtigungen
+notifications-details = Details
+notifications-clear = Leeren
+notifications-count = { $count ->
+        [one] { $count } Benachrichtigung
+       *[other] { $count } Benachrichtigungen
+    }
+
+## Account and users
+
+label-current-password = Aktuelles Passwort:
+label-new-password = Neues Passwort:
+label-confirm-password = Passwort bestätigen:
+label-role = Rolle:
+validation-current-password-required = Aktuelles Passwort ist erforderlich
+validation-new-password-required = Neues Passwort ist erforderlich
+validation-passwords-mismatch = Passwörter stimmen nicht überein
+validation-role-required = Rolle ist erforderlich
+validation-confirm-delete-account = Bestätigen Sie, dass Sie Ihr Konto löschen möchten
+change-password-title = Passwort ändern
+change-password-intro = Das Passwort Ihres Kontos ändern
+change-password-button = Passwort ändern
+delete-account-title = Konto löschen
+delete-account-intro = Ihr Konto löschen und abmelden. Dies kann nicht rückgängig gemacht werden.
+delete-account-understood = Mir ist bewusst, dass mein Konto dauerhaft gelöscht wird
+delete-account-button = Konto löschen
+register-title = Registrieren
+register-intro = Einen neuen Benutzer registrieren
+register-admin-only = Nur Administratoren können Administratoren registrieren
+register-button = Registrieren
+users-title = Benutzerverwaltung
+users-refresh = Aktualisieren
+users-role = Rolle
+users-change-role = Rolle ändern
+users-new-password = Neues Passwort
+users-reset-password = Passwort zurücksetzen
+users-enable = Aktivieren
+users-disable = Deaktivieren
+users-delete = Benutzer löschen
+users-column-username = Benutzername
+users-column-role = Rolle
+users-column-status = Status
+users-active = Aktiv
+users-disabled = Deaktiviert
+
+## Live updates dialog
+
+live-updates-title = Live-Updates
+live-updates-intro = Filmänderungen vom Server sofort empfangen
+live-updates-enable = Live-Updates aktivieren
+live-updates-transport = Transport:
+live-updates-endpoint = Endpunkt:
+live-updates-endpoint-hint = Pfad relativ zur Server-URL oder eine absolute URL
+live-updates-reconnect = Neu verbinden nach:
+live-updates-poll = Ohne Verbindung abfragen alle:
+validation-endpoint-required = Endpunkt ist erforderlich
+validation-endpoint-format = Endpunkt muss ein mit / beginnender Pfad oder eine absolute URL sein
+unit-seconds =  s
+
+## Proxy dialog
+
+proxy-title = Proxy-Einstellungen
+proxy-intro = Proxy für alle Anfragen
+proxy-http = HTTP-Proxy:
+proxy-https = HTTPS-Proxy:
+proxy-socks5 = SOCKS5-Proxy:
+proxy-no-proxy = Kein Proxy für:
+proxy-no-proxy-hint = Kommagetrennte Liste von Hosts, Domains und IP-Bereichen
+proxy-test-connection = Verbindung testen
+proxy-connected = Verbunden: { $status }
+proxy-connection-failed = Verbindung fehlgeschlagen: { $error }
+proxy-testing = Verbindung wird getestet…
+proxy-test-interrupted = Der Verbindungstest wurde unerwartet beendet
+
+## Movies
+
+movie-column-title = Filmtitel
+movie-column-description = Beschreibung
+movie-release-year = Erscheinungsjahr
+movie-genres = Genres
+movie-director = Regie
+movie-rating = Bewertung
+movie-rating-value = { NUMBER($rating, minimumFractionDigits: 1, maximumFractionDigits: 1) } / 10
+movie-runtime = Laufzeit
+movie-runtime-value = { $runtime } Min.
+movie-poster = Poster
+movie-poster-open = Öffnen
+movie-poster-unavailable = Poster nicht verfügbar
+movie-other-fields = Weitere Felder ({ $count })
+movie-count = { $count ->
+        [one] { $count } Film
+       *[other] { $count } Filme
+    }
+movie-count-filtered = { $count ->
+        [one] { $shown } von { $count } Film angezeigt
+       *[other] { $shown } von { $count } Filmen angezeigt
+    }
+movie-sort-hint = Klicken zum Sortieren, erneut klicken zum Umkehren oder Zurücksetzen
+search-hint = Suchen, z. B. year:1990..1999 title:~"star" -desc:empty
+search-help = Wörter werden unscharf mit Titeln abgeglichen. Felder: title:, desc:, year:, id:. ~ für unscharfe Suche, Anführungszeichen für Phrasen, - zum Ausschließen und "empty" für fehlende Werte.
+search-clear = Leeren
+search-saved = Gespeicherte Suchen
+search-delete = Löschen
+
+## Movie dialog
+
+movie-create-title = Film erstellen
+movie-update-title = Film aktualisieren
+movie-create-intro = Einen neuen Film erstellen
+movie-label-title = Titel:
+movie-label-description = Beschreibung:
+movie-label-release-year = Erscheinungsjahr:
+movie-year-suffix =  Jahr
+movie-label-genres = Genres:
+movie-genres-hint = Kommagetrennte Liste von Genres
+movie-label-director = Regie:
+movie-label-rating = Bewertung:
+movie-rating-hint = Von 0 bis 10, leer lassen falls unbekannt
+movie-label-runtime = Laufzeit:
+movie-runtime-hint = In Minuten, leer lassen falls unbekannt
+movie-label-poster-url = Poster-URL:
+movie-label-upload-poster = Poster hochladen:
+movie-upload-poster-hint = Ein Bild auswählen oder auf dieses Fenster ziehen
+movie-browse = Durchsuchen…
+movie-image-filter = Bilder
+movie-create-button = Erstellen
+movie-update-button = Aktualisieren
+validation-title-required = Titel ist erforderlich
+validation-description-required = Beschreibung ist erforderlich
+validation-release-year-required = Erscheinungsjahr ist erforderlich
+validation-rating-range = Bewertung muss eine Zahl zwischen 0 und 10 sein
+validation-runtime-minutes = Laufzeit muss eine ganze Zahl von Minuten sein
+validation-poster-file-missing = Posterdatei existiert nicht
+
+## Main window
+
+label-server-url = Server-URL:
+menu-register = Registrieren
+menu-logout = Abmelden
+menu-account = Konto
+menu-users = Benutzer
+menu-proxy = Proxy
+menu-live = Live
+menu-view = Ansicht
+menu-reset-layout = Layout zurücksetzen
+menu-language = Sprache
+menu-list-movies = Filme auflisten
+menu-clear-selection = Auswahl aufheben
+auto-refresh = Automatisch aktualisieren
+auto-refresh-every = alle 
+status-logged-in = Angemeldet als { $username } ({ $role })
+status-tls-disabled = TLS: Zertifikatsprüfung DEAKTIVIERT
+status-tls = TLS: { $summary }
+status-live = Live: { $state }
+notifications-unread = Benachrichtigungen ({ $count })
+layout-reset = Layout zurückgesetzt
+shortcuts-saved = Tastenkürzel gespeichert
+search-saved-success = Suche „{ $name }“ gespeichert
+
+## Main window actions
+
+login-waiting-title = Warten auf Anmeldung
+login-waiting-intro = Schließen Sie die Anmeldung in Ihrem Browser ab.
+login-waiting-open-again = Anmeldeseite erneut öffnen
+login-success = Angemeldet
+logout-success = Abgemeldet
+register-success = Benutzer { $username } registriert
+change-password-success = Passwort geändert
+delete-account-success = Konto gelöscht
+proxy-applied = Proxy-Einstellungen übernommen
+proxy-apply-failed = Proxy-Einstellungen konnten nicht übernommen werden
+users-list-failed = Benutzer konnten nicht aufgelistet werden
+users-role-changed = Rolle von { $username } auf { $role } geändert
+users-enabled = { $username } aktiviert
+users-enable-failed = Benutzer konnte nicht aktiviert werden
+users-disable-title = Benutzer deaktivieren
+users-disable-confirm = { $username } deaktivieren? Eine Anmeldung ist dann nicht mehr möglich.
+users-disabled-success = Benutzer deaktiviert
+users-disable-failed = Benutzer konnte nicht deaktiviert werden
+users-reset-password-confirm = Passwort von { $username } zurücksetzen?
+users-password-reset = Passwort zurückgesetzt
+users-delete-confirm = { $username } löschen? Dies kann nicht rückgängig gemacht werden.
+users-deleted = Benutzer gelöscht
+movies-list-failed = Filme konnten nicht aufgelistet werden
+movies-refresh-failed = Filme konnten nicht aktualisiert werden
+movie-none-selected = Kein Film ausgewählt
+movie-created = Film „{ $title }“ erstellt
+movie-create-failed = Film konnte nicht erstellt werden
+movie-updated = Film „{ $title }“ aktualisiert
+movie-update-failed = Film konnte nicht aktualisiert werden
+movie-delete-title = Film löschen
+movie-delete-confirm = „{ $title }“ ({ $year }) löschen? Dies kann einige Sekunden lang rückgängig gemacht werden.
+movie-deleted = „{ $title }“ gelöscht
+movie-delete-failed = Film konnte nicht gelöscht werden
+movie-restored = „{ $title }“ wiederhergestellt
+movie-restored-new-id = „{ $title }“ wiederhergestellt, der Server hat jedoch die neue ID { $id } vergeben (vorher { $old_id })
+movie-restore-failed = Film konnte nicht wiederhergestellt werden
+movie-duplicate-title = Doppelter Film
+movie-duplicate-confirm = „{ $title }“ ({ $year }) existiert bereits. Trotzdem erstellen?
+poster-missing-movie-id = Der Server hat keine Film-ID zurückgegeben, das Poster wurde nicht hochgeladen
+poster-upload-failed = Poster konnte nicht hochgeladen werden
+
+## Accessibility
+
+a11y-search-movies = Filme durchsuchen
+a11y-delete-search = Gespeicherte Suche { $name } löschen
+a11y-sorted-ascending = { $column }, aufsteigend sortiert
+a11y-sorted-descending = { $column }, absteigend sortiert
+a11y-poster = Poster von { $title }
+a11y-dismiss = Benachrichtigung schließen
+a11y-auto-refresh-interval = Intervall der automatischen Aktualisierung in Sekunden
+a11y-reset-shortcut = Tastenkürzel von { $command } zurücksetzen
+a11y-command-shortcut = { $command }, { $shortcut }
+a11y-notification = { $severity }: { $message }
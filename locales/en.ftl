@@ -0,0 +1,408 @@
+## Roles
+
+role-admin = Admin
+role-user = User
+role-none = No Role
+
+## Password policy
+
+password-min-length = { $count ->
+        [one] Use at least { $count } character
+       *[other] Use at least { $count } characters
+    }
+password-add-lowercase = Add a lowercase letter
+password-add-uppercase = Add an uppercase letter
+password-add-digit = Add a digit
+password-add-symbol = Add a symbol
+password-no-username = Do not include the username
+
+## Live updates
+
+live-transport-sse = Server-Sent Events
+live-transport-websocket = WebSocket
+live-state-off = Off
+live-state-connecting = Connecting
+live-state-connected = Connected
+live-state-polling = Disconnected, polling
+
+## Commands
+
+command-open-palette = Command Palette
+command-create-movie = Create Movie
+command-list-movies = List Movies
+command-update-movie = Update Movie
+command-delete-movie = Delete Movie
+command-clear-selection = Clear Selection
+command-select-previous = Select Previous Movie
+command-select-next = Select Next Movie
+command-focus-search = Search Movies
+command-register = Register
+command-login = Login
+command-logout = Logout
+command-change-password = Change Password
+command-delete-account = Delete Account
+command-manage-users = Manage Users
+command-proxy-settings = Proxy Settings
+command-live-updates = Live Update Settings
+command-appearance = Appearance Settings
+command-toggle-theme = Toggle Light and Dark Theme
+command-keyboard-shortcuts = Keyboard Shortcuts
+command-toggle-notifications = Toggle Notifications
+command-reset-layout = Reset Layout
+
+## Appearance
+
+language-system = System Language
+theme-light = Light
+theme-dark = Dark
+theme-system = Follow System
+accent-default = Default
+accent-blue = Blue
+accent-green = Green
+accent-purple = Purple
+accent-orange = Orange
+accent-pink = Pink
+
+## TLS
+
+tls-system-trust = system trust
+tls-extra-cas = { $count ->
+        [one] { $count } extra CA
+       *[other] { $count } extra CAs
+    }
+tls-client-cert = client cert ({ $format })
+tls-min-version = min TLS { $version }
+
+## Errors
+
+error-create-client = Failed to create client: { $error }
+error-read-ca-file = Failed to read CA file { $path }: { $error }
+error-read-client-cert = Failed to read client certificate { $path }: { $error }
+error-read-client-key = Failed to read client key { $path }: { $error }
+error-read-file = Failed to read { $path }: { $error }
+error-no-base-url = No base URL provided
+error-unauthorized = Unauthorized
+error-unsupported-live-url = Unsupported live update URL { $url }
+error-no-credentials = No credentials provided
+error-no-role = No role provided
+error-no-password = No password provided
+error-no-session-cookie = No session cookie received
+error-register-user = Failed to register user
+error-log-in = Failed to log in
+error-log-out = Failed to log out
+error-fetch-identity = Failed to fetch user identity
+error-create-movie = Failed to create movie
+error-fetch-movie = Failed to fetch movie
+error-fetch-movies = Failed to fetch movies
+error-update-movie = Failed to update movie
+error-delete-movie = Failed to delete movie
+error-no-movie-found = No movie found
+error-no-movies-found = No movies found
+error-upload-poster = Failed to upload poster
+error-poster-too-large = Poster files may be at most { $limit } MB
+error-poster-not-image = { $path } is not a PNG, JPEG, GIF or WebP image
+error-load-poster = Failed to load poster: { $status }
+error-fetch-users = Failed to fetch users
+error-parse-users = Failed to parse users: { $error }
+error-change-role = Failed to change role
+error-reset-password = Failed to reset password
+error-change-user-state = Failed to change user state
+error-delete-user = Failed to delete user
+error-change-password = Failed to change password
+error-delete-account = Failed to delete account
+error-no-username = No username in identity
+error-not-jwt = Token is not a JWT
+error-event-stream-status = Event stream returned { $status }
+error-connection-closed = Connection closed by server
+error-stream-closed = Stream closed
+error-stream-closed-by-server = Stream closed by server
+error-authorization-listener-stopped = Authorization listener stopped
+error-fetch-discovery = Failed to fetch OIDC discovery document
+error-no-authorization-url = No authorization URL provided
+error-no-token-url = No token URL provided
+error-obtain-token = Failed to obtain token
+error-authorization-timeout = Timed out waiting for authorization
+error-authorization-failed = Authorization failed: { $error }
+error-authorization-state-mismatch = Authorization state mismatch
+error-no-authorization-code = No authorization code received
+oauth-login-complete = Login complete. You can close this window.
+oauth-login-failed = Login failed. You can close this window.
+
+## Search
+
+search-missing-quote = Missing closing quote
+search-invalid-year = Invalid year "{ $value }"
+search-invalid-id = Invalid id "{ $value }"
+search-unknown-field = Unknown field "{ $name }"
+search-missing-value = Missing value for "{ $name }"
+
+## Common
+
+button-save = Save
+button-cancel = Cancel
+button-reset = Reset
+
+## Command palette and shortcuts
+
+palette-hint = Type a command
+palette-no-matches = No matching commands
+shortcuts-hint = Use names like Ctrl+Shift+P, F5 or ArrowDown, leave empty to unbind
+shortcut-unknown-modifier = Unknown modifier "{ $modifier }"
+shortcut-unknown-key = Unknown key "{ $key }"
+shortcut-already-used = { $shortcut } is already used by { $command }
+
+## Appearance dialog
+
+appearance-title = Appearance
+appearance-theme = Theme:
+appearance-accent = Accent Color:
+appearance-ui-scale = UI Scale:
+appearance-ui-scale-hint = Ctrl + Plus and Ctrl + Minus also change the scale
+
+## Dialogs
+
+button-confirm = Confirm
+label-username = Username:
+label-password = Password:
+validation-name-required = Name is required
+validation-username-required = Username is required
+validation-password-required = Password is required
+password-show = Show
+password-hide = Hide
+password-caps-lock = Caps Lock is on
+password-weak = Weak
+password-fair = Fair
+password-good = Good
+password-strong = Strong
+save-search-title = Save Search
+save-search-query = Query: { $query }
+save-search-name = Name:
+save-search-replace = A saved search with this name will be replaced
+login-title = Login
+login-intro = Login to your account
+login-oauth-intro = Login with the configured authorization server
+login-button = Login
+
+## Notifications
+
+severity-info = Info
+severity-success = Success
+severity-warning = Warning
+severity-error = Error
+action-log-in-again = Log in again
+action-retry = Retry
+action-undo = Undo
+notifications-title = Notifications
+notifications-details = Details
+notifications-clear = Clear
+notifications-count = { $count ->
+        [one] { $count } notification
+       *[other] { $count } notifications
+    }
+
+## Account and users
+
+label-current-password = Current Password:
+label-new-password = New Password:
+label-confirm-password = Confirm Password:
+label-role = Role:
+validation-current-password-required = Current password is required
+validation-new-password-required = New password is required
+validation-passwords-mismatch = Passwords do not match
+validation-role-required = Role is required
+validation-confirm-delete-account = Confirm that you want to delete your account
+change-password-title = Change Password
+change-password-intro = Change the password of your account
+change-password-button = Change Password
+delete-account-title = Delete Account
+delete-account-intro = Delete your account and log out. This cannot be undone.
+delete-account-understood = I understand that my account will be deleted permanently
+delete-account-button = Delete Account
+register-title = Register
+register-intro = Register a new user
+register-admin-only = Only admins can register admins
+register-button = Register
+users-title = User Management
+users-refresh = Refresh
+users-role = Role
+users-change-role = Change Role
+users-new-password = New password
+users-reset-password = Reset Password
+users-enable = Enable
+users-disable = Disable
+users-delete = Delete User
+users-column-username = Username
+users-column-role = Role
+users-column-status = Status
+users-active = Active
+users-disabled = Disabled
+
+## Live updates dialog
+
+live-updates-title = Live Updates
+live-updates-intro = Receive movie changes from the server as they happen
+live-updates-enable = Enable live updates
+live-updates-transport = Transport:
+live-updates-endpoint = Endpoint:
+live-updates-endpoint-hint = Path relative to the server URL or an absolute URL
+live-updates-reconnect = Reconnect after:
+live-updates-poll = Poll while disconnected every:
+validation-endpoint-required = Endpoint is required
+validation-endpoint-format = Endpoint must be a path starting with / or an absolute URL
+unit-seconds =  s
+
+## Proxy dialog
+
+proxy-title = Proxy Settings
+proxy-intro = Proxy used for all requests
+proxy-http = HTTP Proxy:
+proxy-https = HTTPS Proxy:
+proxy-socks5 = SOCKS5 Proxy:
+proxy-no-proxy = No Proxy:
+proxy-no-proxy-hint = Comma separated list of hosts, domains and IP ranges
+proxy-test-connection = Test Connection
+proxy-connected = Connected: { $status }
+proxy-connection-failed = Connection failed: { $error }
+proxy-testing = Testing connection…
+proxy-test-interrupted = The connection test stopped unexpectedly
+
+## Movies
+
+movie-column-title = Movie Name
+movie-column-description = Description
+movie-release-year = Release Year
+movie-genres = Genres
+movie-director = Director
+movie-rating = Rating
+movie-rating-value = { NUMBER($rating, minimumFractionDigits: 1, maximumFractionDigits: 1) } / 10
+movie-runtime = Runtime
+movie-runtime-value = { $runtime } min
+movie-poster = Poster
+movie-poster-open = Open
+movie-poster-unavailable = Poster unavailable
+movie-other-fields = Other Fields ({ $count })
+movie-count = { $count ->
+        [one] { $count } movie
+       *[other] { $count } movies
+    }
+movie-count-filtered = { $count ->
+        [one] Showing { $shown } of { $count } movie
+       *[other] Showing { $shown } of { $count } movies
+    }
+movie-sort-hint = Click to sort, click again to reverse or reset the order
+search-hint = Search, e.g. year:1990..1999 title:~"star" -desc:empty
+search-help = Words match titles fuzzily. Fields: title:, desc:, year:, id:. Use ~ for fuzzy matching, quotes for phrases, - to exclude and "empty" for missing values.
+search-clear = Clear
+search-saved = Saved Searches
+search-delete = Delete
+
+## Movie dialog
+
+movie-create-title = Create Movie
+movie-update-title = Update Movie
+movie-create-intro = Create a new movie
+movie-label-title = Title:
+movie-label-description = Description:
+movie-label-release-year = Release Year:
+movie-year-suffix =  Year
+movie-label-genres = Genres:
+movie-genres-hint = Comma separated list of genres
+movie-label-director = Director:
+movie-label-rating = Rating:
+movie-rating-hint = From 0 to 10, leave empty if unknown
+movie-label-runtime = Runtime:
+movie-runtime-hint = In minutes, leave empty if unknown
+movie-label-poster-url = Poster URL:
+movie-label-upload-poster = Upload Poster:
+movie-upload-poster-hint = Pick an image or drop one onto this window
+movie-browse = Browse…
+movie-image-filter = Images
+movie-create-button = Create
+movie-update-button = Update
+validation-title-required = Title is required
+validation-description-required = Description is required
+validation-release-year-required = Release year is required
+validation-rating-range = Rating must be a number between 0 and 10
+validation-runtime-minutes = Runtime must be a whole number of minutes
+validation-poster-file-missing = Poster file does not exist
+
+## Main window
+
+label-server-url = Server URL:
+menu-register = Register
+menu-logout = Logout
+menu-account = Account
+menu-users = Users
+menu-proxy = Proxy
+menu-live = Live
+menu-view = View
+menu-reset-layout = Reset Layout
+menu-language = Language
+menu-list-movies = List Movies
+menu-clear-selection = Clear Selection
+auto-refresh = Auto Refresh
+auto-refresh-every = every 
+status-logged-in = Logged in as { $username } ({ $role })
+status-tls-disabled = TLS: certificate validation DISABLED
+status-tls = TLS: { $summary }
+status-live = Live: { $state }
+notifications-unread = Notifications ({ $count })
+layout-reset = Layout reset
+shortcuts-saved = Keyboard shortcuts saved
+search-saved-success = Saved search "{ $name }"
+
+## Main window actions
+
+login-waiting-title = Waiting for Login
+login-waiting-intro = Complete the login in your browser.
+login-waiting-open-again = Open login page again
+login-success = Logged in
+logout-success = Logged out
+register-success = Registered user { $username }
+change-password-success = Password changed
+delete-account-success = Account deleted
+proxy-applied = Proxy settings applied
+proxy-apply-failed = Failed to apply proxy settings
+users-list-failed = Failed to list users
+users-role-changed = Changed the role of { $username } to { $role }
+users-enabled = Enabled { $username }
+users-enable-failed = Failed to enable user
+users-disable-title = Disable User
+users-disable-confirm = Disable { $username }? They will no longer be able to log in.
+users-disabled-success = User disabled
+users-disable-failed = Failed to disable user
+users-reset-password-confirm = Reset the password of { $username }?
+users-password-reset = Password reset
+users-delete-confirm = Delete { $username }? This cannot be undone.
+users-deleted = User deleted
+movies-list-failed = Failed to list movies
+movies-refresh-failed = Failed to refresh movies
+movie-none-selected = No movie selected
+movie-created = Created movie "{ $title }"
+movie-create-failed = Failed to create a movie
+movie-updated = Updated movie "{ $title }"
+movie-update-failed = Failed to update a movie
+movie-delete-title = Delete Movie
+movie-delete-confirm = Delete "{ $title }" ({ $year })? You can undo this for a few seconds.
+movie-deleted = Deleted "{ $title }"
+movie-delete-failed = Failed to delete a movie
+movie-restored = Restored "{ $title }"
+movie-restored-new-id = Restored "{ $title }", but the server assigned the new id { $id } (was { $old_id })
+movie-restore-failed = Failed to restore the movie
+movie-duplicate-title = Duplicate Movie
+movie-duplicate-confirm = "{ $title }" ({ $year }) already exists. Create it anyway?
+poster-missing-movie-id = The server did not return the movie id, the poster was not uploaded
+poster-upload-failed = Failed to upload the poster
+
+## Accessibility
+
+a11y-search-movies = Search movies
+a11y-delete-search = Delete saved search { $name }
+a11y-sorted-ascending = { $column }, sorted ascending
+a11y-sorted-descending = { $column }, sorted descending
+a11y-poster = Poster of { $title }
+a11y-dismiss = Dismiss notification
+a11y-auto-refresh-interval = Auto refresh interval in seconds
+a11y-reset-shortcut = Reset shortcut of { $command }
+a11y-command-shortcut = { $command }, { $shortcut }
+a11y-notification = { $severity }: { $message }
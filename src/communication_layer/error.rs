@@ -0,0 +1,159 @@
+use std::fmt;
+
+use reqwest::{StatusCode, blocking::Response};
+use serde_json::Value;
+
+use crate::data_types::FieldErrors;
+
+#[derive(Debug)]
+pub struct ApiError {
+    pub context: String,
+    pub status: StatusCode,
+    pub message: String,
+    pub field_errors: FieldErrors,
+    pub body: String,
+}
+
+impl ApiError {
+    pub fn from_response(context: &str, response: Response) -> Self {
+        let status = response.status();
+        let body = response.text().unwrap_or_default();
+
+        let mut field_errors = FieldErrors::new();
+        let mut message = None;
+
+        match serde_json::from_str::<Value>(&body) {
+            Ok(value) => {
+                message = explanation(&value);
+                collect_field_errors(value.get("errors"), &mut field_errors);
+            }
+            Err(_) => {
+                let text = body.trim();
+
+                if !text.is_empty() && !text.starts_with('<') && text.len() <= 200 {
+                    message = Some(text.to_string());
+                }
+            }
+        }
+
+        Self {
+            context: context.to_string(),
+            status,
+            message: message.unwrap_or_else(|| status.to_string()),
+            field_errors,
+            body,
+        }
+    }
+
+    pub fn explanation(&self) -> String {
+        let mut explanation = if self.message == self.status.to_string() {
+            self.message.clone()
+        } else {
+            format!("{} ({})", self.message, self.status)
+        };
+
+        for (field, message) in self.field_errors.iter() {
+            explanation += &format!("\n{}: {}", field, message);
+        }
+
+        explanation
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.context, self.explanation())
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+fn explanation(value: &Value) -> Option<String> {
+    let text = |key: &str| {
+        value
+            .get(key)
+            .and_then(Value::as_str)
+            .filter(|text| !text.is_empty())
+    };
+
+    if let Some(detail) = text("detail") {
+        return Some(match text("title") {
+            Some(title) => format!("{}: {}", title, detail),
+            None => detail.to_string(),
+        });
+    }
+
+    match value.get("error") {
+        Some(Value::String(error)) => {
+            return Some(
+                match text("error_description").or_else(|| text("message")) {
+                    Some(description) => format!("{}: {}", error, description),
+                    None => error.clone(),
+                },
+            );
+        }
+        Some(error @ Value::Object(_)) => {
+            if let Some(message) = explanation(error) {
+                return Some(message);
+            }
+        }
+        _ => {}
+    }
+
+    text("message")
+        .or_else(|| text("title"))
+        .map(str::to_string)
+}
+
+fn collect_field_errors(errors: Option<&Value>, field_errors: &mut FieldErrors) {
+    match errors {
+        Some(Value::Object(map)) => {
+            for (field, messages) in map {
+                if let Some(message) = first_message(messages) {
+                    field_errors.add(field, message);
+                }
+            }
+        }
+        Some(Value::Array(entries)) => {
+            for entry in entries {
+                let field = ["field", "name", "path", "loc", "pointer"]
+                    .iter()
+                    .find_map(|key| entry.get(*key))
+                    .and_then(field_name);
+
+                let message = ["message", "msg", "detail"]
+                    .iter()
+                    .find_map(|key| entry.get(*key))
+                    .and_then(first_message);
+
+                if let (Some(field), Some(message)) = (field, message) {
+                    field_errors.add(&field, message);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn first_message(value: &Value) -> Option<String> {
+    match value {
+        Value::String(message) => Some(message.clone()),
+        Value::Array(messages) => messages.iter().find_map(first_message),
+        _ => None,
+    }
+}
+
+fn field_name(value: &Value) -> Option<String> {
+    match value {
+        Value::String(path) => path
+            .rsplit(['/', '.'])
+            .find(|segment| !segment.is_empty())
+            .map(str::to_string),
+        Value::Array(path) => path
+            .iter()
+            .rev()
+            .find_map(|segment| segment.as_str())
+            .map(str::to_string),
+        _ => None,
+    }
+}
@@ -0,0 +1,54 @@
+use anyhow::{Result, anyhow};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use serde_json::Value;
+
+use crate::{
+    data_types::{Role, UserIdentity},
+    t,
+};
+
+pub fn from_json(value: &Value, role_claim: &str) -> Result<UserIdentity> {
+    let value = value.get("user").unwrap_or(value);
+
+    let username = ["preferred_username", "username", "name", "sub"]
+        .iter()
+        .find_map(|key| value.get(*key).and_then(Value::as_str))
+        .ok_or_else(|| anyhow!(t!("error-no-username")))?
+        .to_string();
+
+    let role = match value.get(role_claim).or_else(|| value.get("roles")) {
+        Some(Value::String(role)) => Role::from(role.clone()),
+        Some(Value::Array(roles)) => {
+            let roles: Vec<Role> = roles
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|role| Role::from(role.to_string()))
+                .collect();
+
+            if roles.contains(&Role::Admin) {
+                Role::Admin
+            } else if roles.contains(&Role::User) {
+                Role::User
+            } else {
+                Role::None
+            }
+        }
+        _ => Role::None,
+    };
+
+    Ok(UserIdentity { username, role })
+}
+
+pub fn from_token(token: &str, role_claim: &str) -> Result<UserIdentity> {
+    let token = token.strip_prefix("Bearer ").unwrap_or(token);
+
+    let payload = token
+        .split('.')
+        .nth(1)
+        .ok_or_else(|| anyhow!(t!("error-not-jwt")))?;
+
+    let claims: Value =
+        serde_json::from_slice(&URL_SAFE_NO_PAD.decode(payload.trim_end_matches('='))?)?;
+
+    from_json(&claims, role_claim)
+}
@@ -0,0 +1,411 @@
+use std::{
+    io::{self, BufRead, BufReader, ErrorKind},
+    net::TcpStream,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Result, anyhow};
+use reqwest::blocking::RequestBuilder;
+use serde_json::Value;
+use tungstenite::{Message, client::IntoClientRequest, http::Request, stream::MaybeTlsStream};
+
+use crate::{
+    data_types::{LiveState, Movie},
+    t,
+};
+
+const STREAM_READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub type Notifier = Arc<dyn Fn() + Send + Sync>;
+
+pub enum LiveEvent {
+    Created(Movie),
+    Updated(Movie),
+    Deleted(i32),
+}
+
+enum StreamMessage {
+    Connected,
+    Event(LiveEvent),
+    Disconnected(String),
+}
+
+struct Subscription {
+    receiver: Receiver<StreamMessage>,
+    stop: Arc<AtomicBool>,
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+pub struct LiveUpdates {
+    subscription: Option<Subscription>,
+    state: LiveState,
+    last_error: Option<String>,
+    retry_at: Instant,
+    last_poll: Instant,
+    token: String,
+    notifier: Option<Notifier>,
+}
+
+impl LiveUpdates {
+    pub fn new() -> Self {
+        Self {
+            subscription: None,
+            state: LiveState::Off,
+            last_error: None,
+            retry_at: Instant::now(),
+            last_poll: Instant::now(),
+            token: String::new(),
+            notifier: None,
+        }
+    }
+
+    pub fn state(&self) -> LiveState {
+        self.state
+    }
+
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    pub fn set_notifier(&mut self, notifier: Notifier) {
+        self.notifier = Some(notifier);
+    }
+
+    pub fn stop(&mut self) {
+        self.subscription = None;
+        self.state = LiveState::Off;
+        self.last_error = None;
+        self.retry_at = Instant::now();
+    }
+
+    pub fn token_changed(&mut self, token: &str) -> bool {
+        if self.token == token {
+            return false;
+        }
+
+        self.token = token.to_string();
+
+        true
+    }
+
+    pub fn should_connect(&self) -> bool {
+        self.subscription.is_none() && Instant::now() >= self.retry_at
+    }
+
+    pub fn should_poll(&mut self, interval: Duration) -> bool {
+        if self.state == LiveState::Connected || self.last_poll.elapsed() < interval {
+            return false;
+        }
+
+        self.last_poll = Instant::now();
+
+        true
+    }
+
+    pub fn connect_sse(&mut self, request: RequestBuilder) {
+        self.spawn(move |send, stop| {
+            let response = request
+                .header("Accept", "text/event-stream")
+                .timeout(STREAM_READ_TIMEOUT)
+                .send()?;
+
+            if !response.status().is_success() {
+                return Err(anyhow!(t!(
+                    "error-event-stream-status",
+                    status = response.status().to_string()
+                )));
+            }
+
+            send(StreamMessage::Connected);
+
+            read_event_stream(BufReader::new(response), send, stop)
+        });
+    }
+
+    pub fn connect_websocket(&mut self, request: Request<()>) {
+        self.spawn(move |send, stop| {
+            let (mut socket, _) = tungstenite::connect(request)?;
+
+            if let Some(stream) = tcp_stream(socket.get_ref()) {
+                stream.set_read_timeout(Some(STREAM_READ_TIMEOUT))?;
+            }
+
+            send(StreamMessage::Connected);
+
+            while !stop.load(Ordering::Relaxed) {
+                match socket.read() {
+                    Ok(Message::Text(text)) => {
+                        if let Some(event) = parse_event("", text.as_str()) {
+                            send(StreamMessage::Event(event));
+                        }
+                    }
+                    Ok(Message::Close(_)) => return Err(anyhow!(t!("error-connection-closed"))),
+                    Ok(_) => {}
+                    Err(tungstenite::Error::Io(e)) if timed_out(&e) => {}
+                    Err(e) => return Err(e.into()),
+                }
+            }
+
+            let _ = socket.close(None);
+
+            Ok(())
+        });
+    }
+
+    pub fn connect_failed(&mut self, error: String, reconnect_delay: Duration) {
+        self.disconnected(error, reconnect_delay);
+    }
+
+    pub fn drain(&mut self, reconnect_delay: Duration) -> Vec<LiveEvent> {
+        let Some(subscription) = &self.subscription else {
+            return Vec::new();
+        };
+
+        let mut events = Vec::new();
+        let mut disconnected = None;
+
+        for message in subscription.receiver.try_iter() {
+            match message {
+                StreamMessage::Connected => {
+                    self.state = LiveState::Connected;
+                    self.last_error = None;
+                }
+                StreamMessage::Event(event) => events.push(event),
+                StreamMessage::Disconnected(error) => disconnected = Some(error),
+            }
+        }
+
+        if let Some(error) = disconnected {
+            self.disconnected(error, reconnect_delay);
+        }
+
+        events
+    }
+
+    fn disconnected(&mut self, error: String, reconnect_delay: Duration) {
+        if self.state == LiveState::Connected {
+            self.last_poll = Instant::now();
+        }
+
+        self.subscription = None;
+        self.state = LiveState::Polling;
+        self.last_error = Some(error);
+        self.retry_at = Instant::now() + reconnect_delay;
+    }
+
+    fn spawn(
+        &mut self,
+        run: impl FnOnce(&dyn Fn(StreamMessage), &AtomicBool) -> Result<()> + Send + 'static,
+    ) {
+        let (sender, receiver) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let notifier = self.notifier.clone();
+
+        let thread_stop = stop.clone();
+        thread::spawn(move || {
+            let send = |message| {
+                if sender.send(message).is_ok()
+                    && let Some(notifier) = &notifier
+                {
+                    notifier();
+                }
+            };
+
+            let error = match run(&send, &thread_stop) {
+                Ok(()) => t!("error-stream-closed"),
+                Err(error) => error.to_string(),
+            };
+
+            if !thread_stop.load(Ordering::Relaxed) {
+                send(StreamMessage::Disconnected(error));
+            }
+        });
+
+        if self.state != LiveState::Polling {
+            self.state = LiveState::Connecting;
+        }
+
+        self.subscription = Some(Subscription { receiver, stop });
+    }
+}
+
+pub fn websocket_request(url: &str, headers: Vec<(&'static str, String)>) -> Result<Request<()>> {
+    let mut request = url.into_client_request()?;
+
+    for (name, value) in headers {
+        request.headers_mut().insert(name, value.parse()?);
+    }
+
+    Ok(request)
+}
+
+fn tcp_stream(stream: &MaybeTlsStream<TcpStream>) -> Option<&TcpStream> {
+    match stream {
+        MaybeTlsStream::Plain(stream) => Some(stream),
+        MaybeTlsStream::NativeTls(stream) => Some(stream.get_ref()),
+        _ => None,
+    }
+}
+
+fn timed_out(error: &io::Error) -> bool {
+    matches!(error.kind(), ErrorKind::TimedOut | ErrorKind::WouldBlock)
+        || error
+            .get_ref()
+            .and_then(|inner| inner.downcast_ref::<reqwest::Error>())
+            .is_some_and(reqwest::Error::is_timeout)
+}
+
+fn read_event_stream(
+    mut reader: impl BufRead,
+    send: &dyn Fn(StreamMessage),
+    stop: &AtomicBool,
+) -> Result<()> {
+    let mut event = String::new();
+    let mut data = String::new();
+    let mut buffer = Vec::new();
+
+    while !stop.load(Ordering::Relaxed) {
+        match reader.read_until(b'\n', &mut buffer) {
+            Ok(0) => return Err(anyhow!(t!("error-stream-closed-by-server"))),
+            Ok(_) => {}
+            Err(e) if timed_out(&e) => continue,
+            Err(e) => return Err(e.into()),
+        }
+
+        let line = String::from_utf8_lossy(&buffer)
+            .trim_end_matches(['\r', '\n'])
+            .to_string();
+        buffer.clear();
+
+        if line.is_empty() {
+            if let Some(live_event) = parse_event(&event, &data) {
+                send(StreamMessage::Event(live_event));
+            }
+
+            event.clear();
+            data.clear();
+        } else if let Some(value) = line.strip_prefix("event:") {
+            event = value.trim().to_string();
+        } else if let Some(value) = line.strip_prefix("data:") {
+            if !data.is_empty() {
+                data.push('\n');
+            }
+
+            data.push_str(value.strip_prefix(' ').unwrap_or(value));
+        }
+    }
+
+    Ok(())
+}
+
+const ENVELOPE_KEYS: [&str; 3] = ["type", "event", "action"];
+
+fn parse_event(event: &str, data: &str) -> Option<LiveEvent> {
+    let value: Value = serde_json::from_str(data).ok()?;
+
+    let kind = match event {
+        "" | "message" => ENVELOPE_KEYS
+            .iter()
+            .find_map(|key| value.get(*key).and_then(Value::as_str))?
+            .to_lowercase(),
+        event => event.to_lowercase(),
+    };
+
+    let payload = match value.get("movie") {
+        Some(movie) => movie.clone(),
+        None => {
+            let mut payload = value.clone();
+
+            if let Some(object) = payload.as_object_mut() {
+                for key in ENVELOPE_KEYS {
+                    object.remove(key);
+                }
+            }
+
+            payload
+        }
+    };
+
+    match kind.as_str() {
+        "created" | "create" => serde_json::from_value(payload).ok().map(LiveEvent::Created),
+        "updated" | "update" => serde_json::from_value(payload).ok().map(LiveEvent::Updated),
+        "deleted" | "delete" => payload
+            .get("id")
+            .or_else(|| value.get("id"))
+            .and_then(Value::as_i64)
+            .map(|id| LiveEvent::Deleted(id as i32)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, collections::VecDeque, io::Read};
+
+    use super::*;
+
+    struct ScriptedReader(VecDeque<io::Result<&'static [u8]>>);
+
+    impl Read for ScriptedReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.pop_front() {
+                Some(Ok(bytes)) => {
+                    buf[..bytes.len()].copy_from_slice(bytes);
+                    Ok(bytes.len())
+                }
+                Some(Err(e)) => Err(e),
+                None => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn event_stream_survives_read_timeouts() {
+        let reader = ScriptedReader(VecDeque::from([
+            Ok(&b"event: deleted\r\nda"[..]),
+            Err(io::Error::from(ErrorKind::TimedOut)),
+            Ok(&b"ta: {\"id\": 7}\r\n\r\n"[..]),
+        ]));
+
+        let deleted = RefCell::new(Vec::new());
+        let send = |message| {
+            if let StreamMessage::Event(LiveEvent::Deleted(id)) = message {
+                deleted.borrow_mut().push(id);
+            }
+        };
+
+        let result = read_event_stream(BufReader::new(reader), &send, &AtomicBool::new(false));
+
+        assert!(result.is_err());
+        assert_eq!(deleted.into_inner(), vec![7]);
+    }
+
+    #[test]
+    fn event_stream_checks_stop_flag_after_timeout() {
+        struct StoppingReader<'a>(&'a AtomicBool);
+
+        impl Read for StoppingReader<'_> {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                self.0.store(true, Ordering::Relaxed);
+
+                Err(io::Error::from(ErrorKind::TimedOut))
+            }
+        }
+
+        let stop = AtomicBool::new(false);
+        let reader = BufReader::new(StoppingReader(&stop));
+
+        assert!(read_event_stream(reader, &|_| {}, &stop).is_ok());
+    }
+}
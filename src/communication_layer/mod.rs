@@ -1,38 +1,617 @@
-use std::{collections::HashMap, io::Read};
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, Read},
+    path::Path,
+    sync::{
+        Arc,
+        mpsc::{self, Receiver, TryRecvError},
+    },
+    thread,
+    time::Duration,
+};
 
 use crate::config::Config;
-use crate::data_types::{Movie, Role};
-use reqwest::{StatusCode, blocking::Client};
+use crate::data_types::{
+    AuthMode, ClientIdentity, IdentitySource, LiveState, LiveTransport, Movie, OAuthFlow,
+    Permission, ProxySettings, Role, SessionState, TlsVersion, User, UserIdentity,
+};
+use crate::t;
+use image::ImageFormat;
+use reqwest::{
+    NoProxy, Proxy, StatusCode, Url,
+    blocking::{
+        Client, ClientBuilder, RequestBuilder, Response,
+        multipart::{Form, Part},
+    },
+    tls::{Certificate, Identity, Version},
+};
+use reqwest_cookie_store::CookieStoreMutex;
+use serde_json::Value;
+
+pub use error::ApiError;
+use live::{LiveEvent, LiveUpdates};
+use oauth::PendingAuthorization;
+pub use posters::{Poster, PosterCache};
+
+mod error;
+mod identity;
+mod live;
+mod oauth;
+mod posters;
 
 use anyhow::{Result, anyhow};
 
+const MAX_POSTER_BYTES: u64 = 10 * 1024 * 1024;
+
+type SessionListener = Box<dyn FnMut(SessionState)>;
+
 pub struct DataLayer {
     pub config: Config,
     pub movies: Vec<Movie>,
-    client: Client,
+    movies_revision: u64,
+    uploaded_posters: Vec<String>,
+    pub users: Vec<User>,
+    client: Option<Client>,
+    client_error: Option<String>,
+    cookies: Arc<CookieStoreMutex>,
+    pending_authorization: Option<PendingAuthorization>,
+    session_state: SessionState,
+    session_listeners: Vec<SessionListener>,
+    identity: Option<UserIdentity>,
+    live: LiveUpdates,
+}
+
+fn build_client(config: &Config, cookies: &Arc<CookieStoreMutex>) -> Result<Client> {
+    client_builder(config, cookies)?
+        .build()
+        .map_err(|e| anyhow!(t!("error-create-client", error = e.to_string())))
+}
+
+fn client_builder(config: &Config, cookies: &Arc<CookieStoreMutex>) -> Result<ClientBuilder> {
+    let tls = &config.tls;
+
+    let mut builder = Client::builder()
+        .user_agent("Simple REST Client/1.0.0")
+        .cookie_provider(cookies.clone())
+        .danger_accept_invalid_certs(tls.accept_invalid_certs);
+
+    for path in tls.root_ca_paths.iter() {
+        let pem = fs::read(path).map_err(|e| {
+            anyhow!(t!(
+                "error-read-ca-file",
+                path = path.as_str(),
+                error = e.to_string()
+            ))
+        })?;
+
+        for cert in Certificate::from_pem_bundle(&pem)? {
+            builder = builder.add_root_certificate(cert);
+        }
+    }
+
+    match &tls.client_identity {
+        ClientIdentity::None => {}
+        ClientIdentity::Pem {
+            cert_path,
+            key_path,
+        } => {
+            let cert = fs::read(cert_path).map_err(|e| {
+                anyhow!(t!(
+                    "error-read-client-cert",
+                    path = cert_path.as_str(),
+                    error = e.to_string()
+                ))
+            })?;
+            let key = fs::read(key_path).map_err(|e| {
+                anyhow!(t!(
+                    "error-read-client-key",
+                    path = key_path.as_str(),
+                    error = e.to_string()
+                ))
+            })?;
+
+            builder = builder.identity(Identity::from_pkcs8_pem(&cert, &key)?);
+        }
+        ClientIdentity::Pkcs12 { path, password } => {
+            let der = fs::read(path).map_err(|e| {
+                anyhow!(t!(
+                    "error-read-client-cert",
+                    path = path.as_str(),
+                    error = e.to_string()
+                ))
+            })?;
+
+            builder = builder.identity(Identity::from_pkcs12_der(&der, password)?);
+        }
+    }
+
+    builder = match tls.min_version {
+        TlsVersion::Default => builder,
+        TlsVersion::Tls1_0 => builder.min_tls_version(Version::TLS_1_0),
+        TlsVersion::Tls1_1 => builder.min_tls_version(Version::TLS_1_1),
+        TlsVersion::Tls1_2 => builder.min_tls_version(Version::TLS_1_2),
+    };
+
+    if !config.proxy.is_empty() {
+        for proxy in build_proxies(&config.proxy)? {
+            builder = builder.proxy(proxy);
+        }
+    }
+
+    Ok(builder)
+}
+
+fn build_proxies(settings: &ProxySettings) -> Result<Vec<Proxy>> {
+    let mut proxies = Vec::new();
+
+    if !settings.http_url.is_empty() {
+        proxies.push(Proxy::http(&settings.http_url)?);
+    }
+
+    if !settings.https_url.is_empty() {
+        proxies.push(Proxy::https(&settings.https_url)?);
+    }
+
+    if !settings.socks5_url.is_empty() {
+        proxies.push(Proxy::all(&settings.socks5_url)?);
+    }
+
+    Ok(proxies
+        .into_iter()
+        .map(|proxy| {
+            let proxy = if settings.username.is_empty() {
+                proxy
+            } else {
+                proxy.basic_auth(&settings.username, &settings.password)
+            };
+
+            proxy.no_proxy(NoProxy::from_string(&settings.no_proxy))
+        })
+        .collect())
+}
+
+fn test_connection(config: &Config) -> Result<StatusCode> {
+    if config.base_url.is_empty() {
+        return Err(anyhow!(t!("error-no-base-url")));
+    }
+
+    let cookies = Arc::new(CookieStoreMutex::default());
+
+    let result = build_client(config, &cookies)?
+        .get(&config.base_url)
+        .send()?;
+
+    Ok(result.status())
+}
+
+pub struct ConnectionTest {
+    receiver: Receiver<Result<StatusCode>>,
+}
+
+impl ConnectionTest {
+    pub fn start(config: Config) -> Self {
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            let _ = sender.send(test_connection(&config));
+        });
+
+        Self { receiver }
+    }
+
+    pub fn poll(&self) -> Option<Result<StatusCode>> {
+        match self.receiver.try_recv() {
+            Ok(result) => Some(result),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(Err(anyhow!(t!("proxy-test-interrupted")))),
+        }
+    }
 }
 
 impl DataLayer {
     pub fn new() -> Self {
-        let client = Client::builder()
-            .user_agent("Simple REST Client/1.0.0")
-            .build()
-            .expect("Failed to create client");
+        let config = Config::load_config();
+        let cookie_store = Config::load_cookies();
+        let session_state = config.current_session_state(&cookie_store);
+
+        let cookies = Arc::new(CookieStoreMutex::new(cookie_store));
+
+        let (client, client_error) = match build_client(&config, &cookies) {
+            Ok(client) => (Some(client), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
 
         Self {
-            config: Config::load_config(),
+            config,
             movies: Vec::new(),
+            movies_revision: 0,
+            uploaded_posters: Vec::new(),
+            users: Vec::new(),
             client,
+            client_error,
+            cookies,
+            pending_authorization: None,
+            session_state,
+            session_listeners: Vec::new(),
+            identity: None,
+            live: LiveUpdates::new(),
+        }
+    }
+
+    pub fn rebuild_client(&mut self) -> Result<()> {
+        match build_client(&self.config, &self.cookies) {
+            Ok(client) => {
+                self.client = Some(client);
+                self.client_error = None;
+
+                Ok(())
+            }
+            Err(e) => {
+                self.client = None;
+                self.client_error = Some(e.to_string());
+
+                Err(e)
+            }
+        }
+    }
+
+    pub fn movies_revision(&self) -> u64 {
+        self.movies_revision
+    }
+
+    pub fn take_uploaded_posters(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.uploaded_posters)
+    }
+
+    pub fn client_error(&self) -> Option<&str> {
+        self.client_error.as_deref()
+    }
+
+    fn client(&self) -> Result<&Client> {
+        self.client
+            .as_ref()
+            .ok_or_else(|| anyhow!(self.client_error.clone().unwrap_or_default()))
+    }
+
+    pub fn session_state(&self) -> SessionState {
+        self.session_state
+    }
+
+    pub fn on_session_changed(&mut self, listener: impl FnMut(SessionState) + 'static) {
+        self.session_listeners.push(Box::new(listener));
+    }
+
+    pub fn identity(&self) -> Option<&UserIdentity> {
+        self.identity.as_ref()
+    }
+
+    pub fn can(&self, permission: Permission) -> bool {
+        match &self.identity {
+            Some(identity) => identity.role.allows(permission),
+            None => {
+                self.session_state == SessionState::Authenticated && Role::User.allows(permission)
+            }
+        }
+    }
+
+    pub fn refresh_identity(&mut self) -> Result<()> {
+        if self.session_state != SessionState::Authenticated {
+            self.identity = None;
+
+            return Ok(());
+        }
+
+        let role_claim = &self.config.identity.role_claim;
+
+        let identity = match self.config.identity.source {
+            IdentitySource::TokenClaims => {
+                identity::from_token(&self.config.creds.current_token, role_claim)?
+            }
+            IdentitySource::Endpoint => {
+                let url = self.resolve_url(&self.config.identity.endpoint);
+
+                let result = self.with_auth(self.client()?.get(url)).send()?;
+
+                match result.error_for_status_ref() {
+                    Ok(_) => identity::from_json(&result.json()?, role_claim)?,
+                    Err(e) if e.status() == Some(StatusCode::UNAUTHORIZED) => {
+                        self.clear_token();
+
+                        return Err(
+                            ApiError::from_response(&t!("error-unauthorized"), result).into()
+                        );
+                    }
+                    Err(_) => {
+                        return Err(
+                            ApiError::from_response(&t!("error-fetch-identity"), result).into()
+                        );
+                    }
+                }
+            }
+        };
+
+        self.identity = Some(identity);
+
+        Ok(())
+    }
+
+    pub fn on_live_update(&mut self, notifier: impl Fn() + Send + Sync + 'static) {
+        self.live.set_notifier(Arc::new(notifier));
+    }
+
+    pub fn live_state(&self) -> LiveState {
+        self.live.state()
+    }
+
+    pub fn live_error(&self) -> Option<&str> {
+        self.live.last_error()
+    }
+
+    pub fn restart_live_updates(&mut self) {
+        self.live.stop();
+    }
+
+    pub fn poll_live_updates(&mut self) -> Option<Result<()>> {
+        let settings = &self.config.live_updates;
+
+        if !settings.enabled || self.config.base_url.is_empty() {
+            if self.live.state() != LiveState::Off {
+                self.live.stop();
+            }
+
+            return None;
+        }
+
+        let reconnect_delay = Duration::from_secs(settings.reconnect_delay_secs);
+        let poll_interval = Duration::from_secs(settings.poll_interval_secs);
+
+        if self.live.token_changed(&self.config.creds.current_token) {
+            self.live.stop();
+        }
+
+        for event in self.live.drain(reconnect_delay) {
+            self.apply_live_event(event);
+        }
+
+        if self.live.should_connect()
+            && let Err(e) = self.subscribe()
+        {
+            self.live.connect_failed(e.to_string(), reconnect_delay);
+        }
+
+        if self.live.should_poll(poll_interval) {
+            Some(self.list_movies(None))
+        } else {
+            None
+        }
+    }
+
+    fn subscribe(&mut self) -> Result<()> {
+        let url = self.resolve_url(&self.config.live_updates.endpoint);
+
+        match self.config.live_updates.transport {
+            LiveTransport::ServerSentEvents => {
+                let client = client_builder(&self.config, &self.cookies)?
+                    .timeout(None)
+                    .build()?;
+
+                self.live.connect_sse(self.with_auth(client.get(url)));
+            }
+            LiveTransport::WebSocket => {
+                let (socket_url, http_url) = if let Some(rest) = url.strip_prefix("https://") {
+                    (format!("wss://{}", rest), url.clone())
+                } else if let Some(rest) = url.strip_prefix("http://") {
+                    (format!("ws://{}", rest), url.clone())
+                } else if let Some(rest) = url.strip_prefix("wss://") {
+                    (url.clone(), format!("https://{}", rest))
+                } else if let Some(rest) = url.strip_prefix("ws://") {
+                    (url.clone(), format!("http://{}", rest))
+                } else {
+                    return Err(anyhow!(t!(
+                        "error-unsupported-live-url",
+                        url = url.as_str()
+                    )));
+                };
+
+                let mut headers = vec![("User-Agent", String::from("Simple REST Client/1.0.0"))];
+                let token = &self.config.creds.current_token;
+
+                match self.config.auth_mode {
+                    AuthMode::Token => headers.push(("Authorization", token.clone())),
+                    AuthMode::OAuth => headers.push(("Authorization", format!("Bearer {}", token))),
+                    AuthMode::CookieSession => {
+                        let cookie_url = Url::parse(&http_url)?;
+
+                        let cookies = self
+                            .cookies
+                            .lock()
+                            .unwrap()
+                            .get_request_values(&cookie_url)
+                            .map(|(name, value)| format!("{}={}", name, value))
+                            .collect::<Vec<_>>()
+                            .join("; ");
+
+                        if !cookies.is_empty() {
+                            headers.push(("Cookie", cookies));
+                        }
+                    }
+                }
+
+                self.live
+                    .connect_websocket(live::websocket_request(&socket_url, headers)?);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn apply_live_event(&mut self, event: LiveEvent) {
+        match event {
+            LiveEvent::Created(movie) | LiveEvent::Updated(movie) => {
+                match self.movies.iter_mut().find(|m| m.id == movie.id) {
+                    Some(existing) => *existing = movie,
+                    None => self.movies.push(movie),
+                }
+            }
+            LiveEvent::Deleted(id) => self.movies.retain(|m| m.id != id),
+        }
+
+        self.movies_revision += 1;
+    }
+
+    fn with_auth(&self, request: RequestBuilder) -> RequestBuilder {
+        match self.config.auth_mode {
+            AuthMode::Token => request.header("Authorization", &self.config.creds.current_token),
+            AuthMode::CookieSession => request,
+            AuthMode::OAuth => request.bearer_auth(&self.config.creds.current_token),
+        }
+    }
+
+    fn refresh_if_expired(&mut self) {
+        if self.config.auth_mode != AuthMode::OAuth
+            || self.config.creds.refresh_token.is_empty()
+            || !self.config.token_expired()
+        {
+            return;
+        }
+
+        let Ok(client) = self.client() else {
+            return;
+        };
+
+        match oauth::refresh(client, &self.config.oauth, &self.config.creds.refresh_token) {
+            Ok(token) => {
+                token.apply(&mut self.config.creds);
+                self.config.store_config();
+
+                self.update_session_state();
+            }
+            Err(e) if oauth::is_rejected_grant(&e) => {
+                self.config.creds.refresh_token.clear();
+                self.clear_token();
+            }
+            Err(_) => {}
+        }
+    }
+
+    fn login_oauth(&mut self, username: String, password: String) -> Result<()> {
+        let client = self.client()?.clone();
+        oauth::discover(&client, &mut self.config.oauth)?;
+
+        let token = match self.config.oauth.flow {
+            OAuthFlow::ClientCredentials => oauth::client_credentials(&client, &self.config.oauth)?,
+            OAuthFlow::Password => {
+                if username.is_empty() || password.is_empty() {
+                    return Err(anyhow!(t!("error-no-credentials")));
+                }
+
+                self.config.creds.username = username.clone();
+                self.config.creds.password = password.clone();
+
+                oauth::password(&client, &self.config.oauth, &username, &password)?
+            }
+            OAuthFlow::AuthorizationCode => {
+                self.pending_authorization =
+                    Some(oauth::begin_authorization_code(&self.config.oauth)?);
+
+                return Ok(());
+            }
+        };
+
+        token.apply(&mut self.config.creds);
+        self.config.store_config();
+
+        self.update_session_state();
+
+        Ok(())
+    }
+
+    pub fn authorization_url_to_open(&mut self) -> Option<String> {
+        match self.pending_authorization.as_mut() {
+            Some(pending) if !pending.opened => {
+                pending.opened = true;
+
+                Some(pending.url.clone())
+            }
+            _ => None,
+        }
+    }
+
+    pub fn pending_authorization_url(&self) -> Option<&str> {
+        self.pending_authorization
+            .as_ref()
+            .map(|pending| pending.url.as_str())
+    }
+
+    pub fn cancel_authorization(&mut self) {
+        self.pending_authorization = None;
+    }
+
+    pub fn poll_authorization(&mut self) -> Option<Result<()>> {
+        let code = self.pending_authorization.as_ref()?.poll()?;
+        let pending = self.pending_authorization.take()?;
+
+        let result = code.and_then(|code| {
+            oauth::exchange_code(self.client()?, &self.config.oauth, &pending, &code)
+        });
+
+        Some(result.map(|token| {
+            token.apply(&mut self.config.creds);
+            self.config.store_config();
+
+            self.update_session_state();
+        }))
+    }
+
+    fn set_token(&mut self, token: String) {
+        self.config.creds.current_token = token;
+        self.config.store_config();
+
+        self.update_session_state();
+    }
+
+    fn store_cookies(&mut self) {
+        self.config.store_cookies(&self.cookies.lock().unwrap());
+
+        self.update_session_state();
+    }
+
+    fn clear_token(&mut self) {
+        self.config.creds.token_expires_at = 0;
+        self.cookies.lock().unwrap().clear();
+        self.config.store_cookies(&self.cookies.lock().unwrap());
+
+        self.set_token(String::new());
+    }
+
+    fn update_session_state(&mut self) {
+        let state = self
+            .config
+            .current_session_state(&self.cookies.lock().unwrap());
+
+        if state != self.session_state {
+            self.session_state = state;
+            self.identity = None;
+            self.users.clear();
+
+            if state == SessionState::Authenticated {
+                let _ = self.refresh_identity();
+            }
+
+            for listener in self.session_listeners.iter_mut() {
+                listener(state);
+            }
         }
     }
 
     pub fn register(&self, username: String, password: String, role: Role) -> Result<()> {
         if self.config.base_url.is_empty() {
-            return Err(anyhow!("No base URL provided"));
+            return Err(anyhow!(t!("error-no-base-url")));
         }
 
         if username.is_empty() || password.is_empty() || role == Role::None {
-            return Err(anyhow!("No credentials provided"));
+            return Err(anyhow!(t!("error-no-credentials")));
         }
 
         let mut creds = HashMap::new();
@@ -47,29 +626,33 @@ impl DataLayer {
                 creds.insert("role", "admin".into());
             }
             Role::None => {
-                return Err(anyhow!("No role provided"));
+                return Err(anyhow!(t!("error-no-role")));
             }
         }
 
         let result = self
-            .client
+            .client()?
             .post(format!("{}/register", self.config.base_url))
             .body(serde_json::to_string(&creds).expect("Failed to serialize user"))
             .send()?;
 
         match result.error_for_status_ref() {
             Ok(_) => Ok(()),
-            Err(e) => Err(anyhow!("Failed to register user: {}", e)),
+            Err(_) => Err(ApiError::from_response(&t!("error-register-user"), result).into()),
         }
     }
 
     pub fn login(&mut self, username: String, password: String) -> Result<()> {
         if self.config.base_url.is_empty() {
-            return Err(anyhow!("No base URL provided"));
+            return Err(anyhow!(t!("error-no-base-url")));
+        }
+
+        if self.config.auth_mode == AuthMode::OAuth {
+            return self.login_oauth(username, password);
         }
 
         if username.is_empty() || password.is_empty() {
-            return Err(anyhow!("No credentials provided"));
+            return Err(anyhow!(t!("error-no-credentials")));
         }
 
         self.config.creds.username = username.clone();
@@ -80,155 +663,203 @@ impl DataLayer {
         creds.insert("password", password);
 
         let result = self
-            .client
+            .client()?
             .post(format!("{}/login", self.config.base_url))
             .body(serde_json::to_string(&creds).expect("Failed to serialize user"))
             .send()?;
 
         match result.error_for_status_ref() {
-            Ok(_) => {
-                let token = result.json::<HashMap<String, String>>()?;
+            Ok(_) => match self.config.auth_mode {
+                AuthMode::Token => {
+                    let mut token = result.json::<HashMap<String, String>>()?;
 
-                self.config.creds.current_token = token["token"].clone();
+                    self.set_token(token.remove("token").unwrap_or_default());
 
-                self.config.store_config();
+                    Ok(())
+                }
+                AuthMode::CookieSession => {
+                    self.config.store_config();
+                    self.store_cookies();
 
-                Ok(())
-            }
-            Err(e) => Err(anyhow!("Failed to log in: {}", e)),
+                    if self.session_state == SessionState::Authenticated {
+                        Ok(())
+                    } else {
+                        Err(anyhow!(t!("error-no-session-cookie")))
+                    }
+                }
+                AuthMode::OAuth => unreachable!("OAuth logins are handled by login_oauth"),
+            },
+            Err(_) => Err(ApiError::from_response(&t!("error-log-in"), result).into()),
         }
     }
 
     pub fn logout(&mut self) -> Result<()> {
         if self.config.base_url.is_empty() {
-            return Err(anyhow!("No base URL provided"));
+            return Err(anyhow!(t!("error-no-base-url")));
         }
 
-        let result = self
-            .client
-            .post(format!("{}/logout", self.config.base_url))
-            .header("Authorization", &self.config.creds.current_token)
-            .send()?;
+        if self.config.auth_mode == AuthMode::OAuth {
+            self.config.creds.refresh_token.clear();
+            self.clear_token();
+
+            return Ok(());
+        }
+
+        let request = self
+            .client()?
+            .post(format!("{}/logout", self.config.base_url));
+
+        let result = self.with_auth(request).send()?;
 
         match result.error_for_status_ref() {
             Ok(_) => {
-                self.config.creds.current_token.clear();
-
-                self.config.store_config();
+                self.clear_token();
 
                 Ok(())
             }
-            Err(e) => Err(anyhow!("Failed to log out: {}", e)),
+            Err(_) => Err(ApiError::from_response(&t!("error-log-out"), result).into()),
         }
     }
 
-    pub fn create_movie(&mut self, movie: Movie) -> Result<()> {
+    pub fn create_movie(&mut self, movie: Movie) -> Result<Option<i32>> {
         if self.config.base_url.is_empty() {
-            return Err(anyhow!("No base URL provided"));
+            return Err(anyhow!(t!("error-no-base-url")));
         }
 
+        self.refresh_if_expired();
+
         if movie.id == -1 {
-            return Ok(());
+            return Ok(None);
         }
 
-        let result = self
-            .client
+        let request = self
+            .client()?
             .post(format!("{}/movie/create", self.config.base_url))
-            .body(serde_json::to_string(&movie).expect("Failed to serialize movie"))
-            .header("Authorization", &self.config.creds.current_token)
-            .send()?;
+            .body(serde_json::to_string(&movie).expect("Failed to serialize movie"));
+
+        let result = self.with_auth(request).send()?;
 
         match result.error_for_status_ref() {
-            Ok(_) => Ok(()),
+            Ok(_) => {
+                let created = result.json::<Value>().ok();
+
+                let id = created.and_then(|value| {
+                    value
+                        .get("movie")
+                        .unwrap_or(&value)
+                        .get("id")
+                        .and_then(Value::as_i64)
+                        .map(|id| id as i32)
+                });
+
+                match id {
+                    Some(id) => {
+                        self.movies.push(Movie { id, ..movie });
+                        self.movies_revision += 1;
+                    }
+                    None => {
+                        let _ = self.list_movies(None);
+                    }
+                }
+
+                Ok(id)
+            }
             Err(e) if e.status() == Some(StatusCode::UNAUTHORIZED) => {
-                self.config.creds.current_token.clear();
-                self.config.store_config();
+                self.clear_token();
 
-                Err(anyhow!("Unauthorized"))
+                Err(ApiError::from_response(&t!("error-unauthorized"), result).into())
             }
-            Err(e) => Err(anyhow!("Failed to create movie: {}", e)),
+            Err(_) => Err(ApiError::from_response(&t!("error-create-movie"), result).into()),
         }
     }
 
-    pub fn list_movies(&mut self, id: Option<i32>) -> Result<Vec<Movie>> {
+    pub fn list_movies(&mut self, id: Option<i32>) -> Result<()> {
         if self.config.base_url.is_empty() {
-            return Err(anyhow!("No base URL provided"));
+            return Err(anyhow!(t!("error-no-base-url")));
         }
 
+        self.refresh_if_expired();
+
         match id {
             Some(id) => {
                 if id == -1 {
-                    return Ok(Vec::new());
+                    return Ok(());
                 }
 
-                let mut result = self
-                    .client
-                    .get(format!("{}/movie/list/{}", self.config.base_url, id))
-                    .header("Authorization", &self.config.creds.current_token)
-                    .send()?;
+                let request = self
+                    .client()?
+                    .get(format!("{}/movie/list/{}", self.config.base_url, id));
+
+                let mut result = self.with_auth(request).send()?;
 
                 match result.error_for_status_ref() {
                     Ok(_) => {
                         let mut body = String::new();
                         let _ = result.read_to_string(&mut body)?;
-                        let data: HashMap<String, Movie> = match serde_json::from_str(&body) {
-                            Ok(data) => data,
-                            Err(_) => HashMap::<String, Movie>::new(),
-                        };
+                        let mut data: HashMap<String, Movie> =
+                            serde_json::from_str(&body).unwrap_or_default();
 
-                        if data.is_empty() {
-                            self.movies = vec![data["movie"].clone()];
+                        match data.remove("movie") {
+                            Some(movie) => {
+                                self.movies = vec![movie];
+                                self.movies_revision += 1;
 
-                            Ok(self.movies.clone())
-                        } else {
-                            self.movies.clear();
+                                Ok(())
+                            }
+                            None => {
+                                self.movies.clear();
+                                self.movies_revision += 1;
 
-                            Err(anyhow!("No movie found"))
+                                Err(anyhow!(t!("error-no-movie-found")))
+                            }
                         }
                     }
                     Err(e) if e.status() == Some(StatusCode::UNAUTHORIZED) => {
-                        self.config.creds.current_token.clear();
-                        self.config.store_config();
+                        self.clear_token();
 
-                        Err(anyhow!("Unauthorized"))
+                        Err(ApiError::from_response(&t!("error-unauthorized"), result).into())
                     }
-                    Err(e) => Err(anyhow!("Failed to fetch movie: {}", e)),
+                    Err(_) => Err(ApiError::from_response(&t!("error-fetch-movie"), result).into()),
                 }
             }
 
             None => {
-                let mut result = self
-                    .client
-                    .get(format!("{}/movie/list", self.config.base_url))
-                    .header("Authorization", &self.config.creds.current_token)
-                    .send()?;
+                let request = self
+                    .client()?
+                    .get(format!("{}/movie/list", self.config.base_url));
+
+                let mut result = self.with_auth(request).send()?;
 
                 match result.error_for_status_ref() {
                     Ok(_) => {
                         let mut body = String::new();
                         let _ = result.read_to_string(&mut body)?;
-                        let data: HashMap<String, Vec<Movie>> = match serde_json::from_str(&body) {
-                            Ok(data) => data,
-                            Err(_) => HashMap::<String, Vec<Movie>>::new(),
-                        };
+                        let mut data: HashMap<String, Vec<Movie>> =
+                            serde_json::from_str(&body).unwrap_or_default();
 
-                        if !data.is_empty() {
-                            self.movies = data["movies"].clone();
+                        match data.remove("movies") {
+                            Some(movies) => {
+                                self.movies = movies;
+                                self.movies_revision += 1;
 
-                            Ok(self.movies.clone())
-                        } else {
-                            self.movies.clear();
+                                Ok(())
+                            }
+                            None => {
+                                self.movies.clear();
+                                self.movies_revision += 1;
 
-                            Err(anyhow!("No movies found"))
+                                Err(anyhow!(t!("error-no-movies-found")))
+                            }
                         }
                     }
                     Err(e) if e.status() == Some(StatusCode::UNAUTHORIZED) => {
-                        self.config.creds.current_token.clear();
-                        self.config.store_config();
+                        self.clear_token();
 
-                        Err(anyhow!("Unauthorized"))
+                        Err(ApiError::from_response(&t!("error-unauthorized"), result).into())
+                    }
+                    Err(_) => {
+                        Err(ApiError::from_response(&t!("error-fetch-movies"), result).into())
                     }
-                    Err(e) => Err(anyhow!("Failed to fetch movies: {}", e)),
                 }
             }
         }
@@ -236,59 +867,370 @@ impl DataLayer {
 
     pub fn update_movie(&mut self, movie: Movie) -> Result<()> {
         if self.config.base_url.is_empty() {
-            return Err(anyhow!("No base URL provided"));
+            return Err(anyhow!(t!("error-no-base-url")));
         }
 
+        self.refresh_if_expired();
+
         if movie.id == -1 || movie == Movie::default() {
             return Ok(());
         }
 
-        let result = self
-            .client
+        let request = self
+            .client()?
             .post(format!(
                 "{}/movie/update/{}",
                 self.config.base_url, movie.id
             ))
-            .body(serde_json::to_string(&movie).expect("Failed to serialize movie"))
-            .header("Authorization", &self.config.creds.current_token)
-            .send()?;
+            .body(serde_json::to_string(&movie).expect("Failed to serialize movie"));
+
+        let result = self.with_auth(request).send()?;
 
         match result.error_for_status_ref() {
-            Ok(_) => Ok(()),
+            Ok(_) => {
+                if let Some(existing) = self.movies.iter_mut().find(|m| m.id == movie.id) {
+                    *existing = movie;
+                    self.movies_revision += 1;
+                }
+
+                Ok(())
+            }
             Err(e) if e.status() == Some(StatusCode::UNAUTHORIZED) => {
-                self.config.creds.current_token.clear();
-                self.config.store_config();
+                self.clear_token();
 
-                Err(anyhow!("Unauthorized"))
+                Err(ApiError::from_response(&t!("error-unauthorized"), result).into())
             }
-            Err(e) => Err(anyhow!("Failed to update movie: {}", e)),
+            Err(_) => Err(ApiError::from_response(&t!("error-update-movie"), result).into()),
         }
     }
 
     pub fn delete_movie(&mut self, id: i32) -> Result<()> {
         if self.config.base_url.is_empty() {
-            return Err(anyhow!("No base URL provided"));
+            return Err(anyhow!(t!("error-no-base-url")));
         }
 
+        self.refresh_if_expired();
+
         if id == -1 {
             return Ok(());
         }
 
-        let result = self
-            .client
-            .delete(format!("{}/movie/delete/{}", self.config.base_url, id))
-            .header("Authorization", &self.config.creds.current_token)
-            .send()?;
+        let request = self
+            .client()?
+            .delete(format!("{}/movie/delete/{}", self.config.base_url, id));
+
+        let result = self.with_auth(request).send()?;
 
         match result.error_for_status_ref() {
-            Ok(_) => Ok(()),
+            Ok(_) => {
+                self.movies.retain(|m| m.id != id);
+                self.movies_revision += 1;
+
+                Ok(())
+            }
             Err(e) if e.status() == Some(StatusCode::UNAUTHORIZED) => {
-                self.config.creds.current_token.clear();
-                self.config.store_config();
+                self.clear_token();
 
-                Err(anyhow!("Unauthorized"))
+                Err(ApiError::from_response(&t!("error-unauthorized"), result).into())
             }
-            Err(e) => Err(anyhow!("Failed to delete movie: {}", e)),
+            Err(_) => Err(ApiError::from_response(&t!("error-delete-movie"), result).into()),
+        }
+    }
+
+    fn is_same_origin(&self, url: &str) -> bool {
+        match (Url::parse(url), Url::parse(&self.config.base_url)) {
+            (Ok(url), Ok(base_url)) => url.origin() == base_url.origin(),
+            _ => false,
+        }
+    }
+
+    pub fn resolve_url(&self, url: &str) -> String {
+        if url.contains("://") {
+            url.to_string()
+        } else if url.starts_with('/') {
+            format!("{}{}", self.config.base_url, url)
+        } else {
+            format!("{}/{}", self.config.base_url, url)
+        }
+    }
+
+    pub fn upload_poster(&mut self, id: i32, path: &Path) -> Result<String> {
+        let read_error = |e: io::Error| {
+            anyhow!(t!(
+                "error-read-file",
+                path = path.display().to_string(),
+                error = e.to_string()
+            ))
+        };
+
+        if fs::metadata(path).map_err(read_error)?.len() > MAX_POSTER_BYTES {
+            return Err(anyhow!(t!(
+                "error-poster-too-large",
+                limit = MAX_POSTER_BYTES / (1024 * 1024)
+            )));
+        }
+
+        let bytes = fs::read(path).map_err(read_error)?;
+
+        let format = image::guess_format(&bytes)
+            .ok()
+            .filter(|format| {
+                matches!(
+                    format,
+                    ImageFormat::Png | ImageFormat::Jpeg | ImageFormat::Gif | ImageFormat::WebP
+                )
+            })
+            .ok_or_else(|| {
+                anyhow!(t!(
+                    "error-poster-not-image",
+                    path = path.display().to_string()
+                ))
+            })?;
+
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "poster".to_string());
+
+        let part = Part::bytes(bytes)
+            .file_name(file_name)
+            .mime_str(format.to_mime_type())?;
+        let form = Form::new().part("poster", part);
+
+        let request = self
+            .client()?
+            .post(format!("{}/movie/{}/poster", self.config.base_url, id))
+            .multipart(form);
+
+        let result = self.send_authorized(request, &t!("error-upload-poster"))?;
+
+        let poster_url = result
+            .json::<Value>()
+            .ok()
+            .and_then(|value| {
+                value
+                    .get("movie")
+                    .unwrap_or(&value)
+                    .get("poster_url")
+                    .and_then(Value::as_str)
+                    .map(String::from)
+            })
+            .unwrap_or_else(|| format!("/movie/{}/poster", id));
+
+        if let Some(movie) = self.movies.iter_mut().find(|m| m.id == id) {
+            movie.poster_url = Some(poster_url.clone());
+            self.movies_revision += 1;
+        }
+
+        self.uploaded_posters.push(poster_url.clone());
+
+        Ok(poster_url)
+    }
+
+    fn user_url(&self, template: &str, id: i32) -> String {
+        format!(
+            "{}{}",
+            self.config.base_url,
+            template.replace("{id}", &id.to_string())
+        )
+    }
+
+    fn send_authorized(&mut self, request: RequestBuilder, context: &str) -> Result<Response> {
+        if self.config.base_url.is_empty() {
+            return Err(anyhow!(t!("error-no-base-url")));
+        }
+
+        self.refresh_if_expired();
+
+        let result = self.with_auth(request).send()?;
+
+        match result.error_for_status_ref() {
+            Ok(_) => Ok(result),
+            Err(e) if e.status() == Some(StatusCode::UNAUTHORIZED) => {
+                self.clear_token();
+
+                Err(ApiError::from_response(&t!("error-unauthorized"), result).into())
+            }
+            Err(_) => Err(ApiError::from_response(context, result).into()),
+        }
+    }
+
+    pub fn list_users(&mut self) -> Result<()> {
+        let request = self.client()?.get(format!(
+            "{}{}",
+            self.config.base_url, self.config.user_endpoints.list
+        ));
+
+        let result = self.send_authorized(request, &t!("error-fetch-users"))?;
+
+        let mut data: HashMap<String, Vec<User>> = serde_json::from_str(&result.text()?)
+            .map_err(|e| anyhow!(t!("error-parse-users", error = e.to_string())))?;
+
+        self.users = data.remove("users").unwrap_or_default();
+
+        Ok(())
+    }
+
+    pub fn change_user_role(&mut self, id: i32, role: Role) -> Result<()> {
+        if role == Role::None {
+            return Err(anyhow!(t!("error-no-role")));
+        }
+
+        let mut body = HashMap::new();
+        body.insert("role", role.api_name());
+
+        let request = self
+            .client()?
+            .post(self.user_url(&self.config.user_endpoints.change_role, id))
+            .body(serde_json::to_string(&body).expect("Failed to serialize role"));
+
+        self.send_authorized(request, &t!("error-change-role"))?;
+
+        if let Some(user) = self.users.iter_mut().find(|user| user.id == id) {
+            user.role = role.api_name().to_string();
+        }
+
+        Ok(())
+    }
+
+    pub fn reset_user_password(&mut self, id: i32, password: String) -> Result<()> {
+        if password.is_empty() {
+            return Err(anyhow!(t!("error-no-password")));
+        }
+
+        let mut body = HashMap::new();
+        body.insert("password", password);
+
+        let request = self
+            .client()?
+            .post(self.user_url(&self.config.user_endpoints.reset_password, id))
+            .body(serde_json::to_string(&body).expect("Failed to serialize password"));
+
+        self.send_authorized(request, &t!("error-reset-password"))?;
+
+        Ok(())
+    }
+
+    pub fn set_user_disabled(&mut self, id: i32, disabled: bool) -> Result<()> {
+        let mut body = HashMap::new();
+        body.insert("disabled", disabled);
+
+        let request = self
+            .client()?
+            .post(self.user_url(&self.config.user_endpoints.disable, id))
+            .body(serde_json::to_string(&body).expect("Failed to serialize user state"));
+
+        self.send_authorized(request, &t!("error-change-user-state"))?;
+
+        if let Some(user) = self.users.iter_mut().find(|user| user.id == id) {
+            user.disabled = disabled;
+        }
+
+        Ok(())
+    }
+
+    pub fn delete_user(&mut self, id: i32) -> Result<()> {
+        let request = self
+            .client()?
+            .delete(self.user_url(&self.config.user_endpoints.delete, id));
+
+        self.send_authorized(request, &t!("error-delete-user"))?;
+
+        self.users.retain(|user| user.id != id);
+
+        Ok(())
+    }
+
+    pub fn change_password(&mut self, old_password: String, new_password: String) -> Result<()> {
+        if old_password.is_empty() || new_password.is_empty() {
+            return Err(anyhow!(t!("error-no-password")));
+        }
+
+        let mut body = HashMap::new();
+        body.insert("old_password", old_password);
+        body.insert("new_password", new_password.clone());
+
+        let request = self
+            .client()?
+            .post(format!(
+                "{}{}",
+                self.config.base_url, self.config.user_endpoints.change_own_password
+            ))
+            .body(serde_json::to_string(&body).expect("Failed to serialize password"));
+
+        let result = self.send_authorized(request, &t!("error-change-password"))?;
+
+        let token = serde_json::from_str::<HashMap<String, String>>(&result.text()?)
+            .ok()
+            .and_then(|mut data| data.remove("token"));
+
+        self.config.creds.password = new_password;
+
+        match token {
+            Some(token) => self.set_token(token),
+            None => self.config.store_config(),
+        }
+
+        Ok(())
+    }
+
+    pub fn delete_account(&mut self, password: String) -> Result<()> {
+        if password.is_empty() {
+            return Err(anyhow!(t!("error-no-password")));
+        }
+
+        let mut body = HashMap::new();
+        body.insert("password", password);
+
+        let request = self
+            .client()?
+            .delete(format!(
+                "{}{}",
+                self.config.base_url, self.config.user_endpoints.delete_own_account
+            ))
+            .body(serde_json::to_string(&body).expect("Failed to serialize password"));
+
+        self.send_authorized(request, &t!("error-delete-account"))?;
+
+        self.config.creds.username.clear();
+        self.config.creds.password.clear();
+        self.config.creds.refresh_token.clear();
+        self.movies.clear();
+        self.movies_revision += 1;
+        self.users.clear();
+
+        self.clear_token();
+
+        Ok(())
+    }
+}
+
+impl Drop for DataLayer {
+    fn drop(&mut self) {
+        if let Ok(cookies) = self.cookies.lock() {
+            self.config.store_cookies(&cookies);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_client_for_every_tls_version() {
+        let cookies = Arc::new(CookieStoreMutex::default());
+
+        for version in [
+            TlsVersion::Default,
+            TlsVersion::Tls1_0,
+            TlsVersion::Tls1_1,
+            TlsVersion::Tls1_2,
+        ] {
+            let mut config = Config::default();
+            config.tls.min_version = version;
+
+            assert!(build_client(&config, &cookies).is_ok());
         }
     }
 }
@@ -0,0 +1,616 @@
+use std::{
+    io::{BufRead, BufReader, ErrorKind, Write},
+    net::TcpListener,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, TryRecvError},
+    },
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Result, anyhow};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use rand::RngCore;
+use reqwest::{StatusCode, Url, blocking::Client};
+use serde::Deserialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    communication_layer::ApiError,
+    data_types::{Credentials, OAuthSettings},
+    t,
+};
+
+const AUTHORIZATION_TIMEOUT: Duration = Duration::from_secs(300);
+const CALLBACK_READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Deserialize)]
+struct Discovery {
+    authorization_endpoint: Option<String>,
+    token_endpoint: String,
+}
+
+#[derive(Deserialize)]
+pub struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    refresh_token: Option<String>,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+pub struct PendingAuthorization {
+    pub url: String,
+    pub opened: bool,
+    redirect_uri: String,
+    verifier: String,
+    receiver: Receiver<Result<String>>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl TokenResponse {
+    pub fn apply(self, creds: &mut Credentials) {
+        creds.current_token = self.access_token;
+
+        if let Some(refresh_token) = self.refresh_token {
+            creds.refresh_token = refresh_token;
+        }
+
+        creds.token_expires_at = match self.expires_in {
+            Some(expires_in) => {
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs()
+                    + expires_in
+            }
+            None => 0,
+        };
+    }
+}
+
+impl PendingAuthorization {
+    pub fn poll(&self) -> Option<Result<String>> {
+        match self.receiver.try_recv() {
+            Ok(result) => Some(result),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => {
+                Some(Err(anyhow!(t!("error-authorization-listener-stopped"))))
+            }
+        }
+    }
+}
+
+impl Drop for PendingAuthorization {
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+pub fn is_rejected_grant(error: &anyhow::Error) -> bool {
+    let Some(api_error) = error.downcast_ref::<ApiError>() else {
+        return false;
+    };
+
+    let invalid_grant = serde_json::from_str::<Value>(&api_error.body)
+        .ok()
+        .and_then(|body| {
+            body.get("error")?
+                .as_str()
+                .map(|error| error == "invalid_grant")
+        })
+        .unwrap_or_default();
+
+    invalid_grant
+        || api_error.status == StatusCode::BAD_REQUEST
+        || api_error.status == StatusCode::UNAUTHORIZED
+}
+
+pub fn discover(client: &Client, settings: &mut OAuthSettings) -> Result<()> {
+    if settings.discovery_url.is_empty() {
+        return Ok(());
+    }
+
+    let result = client.get(&settings.discovery_url).send()?;
+
+    match result.error_for_status_ref() {
+        Ok(_) => {
+            let discovery = result.json::<Discovery>()?;
+
+            settings.token_url = discovery.token_endpoint;
+
+            if let Some(authorization_url) = discovery.authorization_endpoint {
+                settings.authorization_url = authorization_url;
+            }
+
+            Ok(())
+        }
+        Err(_) => Err(ApiError::from_response(&t!("error-fetch-discovery"), result).into()),
+    }
+}
+
+pub fn client_credentials(client: &Client, settings: &OAuthSettings) -> Result<TokenResponse> {
+    request_token(client, settings, vec![("grant_type", "client_credentials")])
+}
+
+pub fn password(
+    client: &Client,
+    settings: &OAuthSettings,
+    username: &str,
+    password: &str,
+) -> Result<TokenResponse> {
+    request_token(
+        client,
+        settings,
+        vec![
+            ("grant_type", "password"),
+            ("username", username),
+            ("password", password),
+        ],
+    )
+}
+
+pub fn refresh(
+    client: &Client,
+    settings: &OAuthSettings,
+    refresh_token: &str,
+) -> Result<TokenResponse> {
+    request_token(
+        client,
+        settings,
+        vec![
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ],
+    )
+}
+
+pub fn exchange_code(
+    client: &Client,
+    settings: &OAuthSettings,
+    pending: &PendingAuthorization,
+    code: &str,
+) -> Result<TokenResponse> {
+    request_token(
+        client,
+        settings,
+        vec![
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", &pending.redirect_uri),
+            ("code_verifier", &pending.verifier),
+        ],
+    )
+}
+
+pub fn begin_authorization_code(settings: &OAuthSettings) -> Result<PendingAuthorization> {
+    if settings.authorization_url.is_empty() {
+        return Err(anyhow!(t!("error-no-authorization-url")));
+    }
+
+    let listener = TcpListener::bind(("127.0.0.1", settings.redirect_port))?;
+    let redirect_uri = format!(
+        "http://127.0.0.1:{}/callback",
+        listener.local_addr()?.port()
+    );
+
+    let verifier = random_string(32);
+    let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()));
+    let state = random_string(16);
+
+    let mut url = Url::parse(&settings.authorization_url)?;
+    url.query_pairs_mut()
+        .append_pair("response_type", "code")
+        .append_pair("client_id", &settings.client_id)
+        .append_pair("redirect_uri", &redirect_uri)
+        .append_pair("code_challenge", &challenge)
+        .append_pair("code_challenge_method", "S256")
+        .append_pair("state", &state);
+
+    if !settings.scope.is_empty() {
+        url.query_pairs_mut().append_pair("scope", &settings.scope);
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let stop = cancelled.clone();
+
+    thread::spawn(move || {
+        let _ = sender.send(wait_for_code(listener, &state, &stop));
+    });
+
+    Ok(PendingAuthorization {
+        url: url.to_string(),
+        opened: false,
+        redirect_uri,
+        verifier,
+        receiver,
+        cancelled,
+    })
+}
+
+fn request_token<'a>(
+    client: &Client,
+    settings: &'a OAuthSettings,
+    mut params: Vec<(&'a str, &'a str)>,
+) -> Result<TokenResponse> {
+    if settings.token_url.is_empty() {
+        return Err(anyhow!(t!("error-no-token-url")));
+    }
+
+    params.push(("client_id", &settings.client_id));
+
+    if !settings.client_secret.is_empty() {
+        params.push(("client_secret", &settings.client_secret));
+    }
+
+    if !settings.scope.is_empty() {
+        params.push(("scope", &settings.scope));
+    }
+
+    let result = client.post(&settings.token_url).form(&params).send()?;
+
+    match result.error_for_status_ref() {
+        Ok(_) => Ok(result.json::<TokenResponse>()?),
+        Err(_) => Err(ApiError::from_response(&t!("error-obtain-token"), result).into()),
+    }
+}
+
+fn wait_for_code(listener: TcpListener, state: &str, cancelled: &AtomicBool) -> Result<String> {
+    listener.set_nonblocking(true)?;
+
+    let started = Instant::now();
+
+    let (mut stream, path) = loop {
+        if cancelled.load(Ordering::Relaxed) {
+            return Err(anyhow!(t!("error-authorization-listener-stopped")));
+        }
+
+        if started.elapsed() > AUTHORIZATION_TIMEOUT {
+            return Err(anyhow!(t!("error-authorization-timeout")));
+        }
+
+        match listener.accept() {
+            Ok((mut stream, _)) => {
+                stream.set_nonblocking(false)?;
+                stream.set_read_timeout(Some(CALLBACK_READ_TIMEOUT))?;
+
+                let mut request_line = String::new();
+
+                if BufReader::new(&stream)
+                    .read_line(&mut request_line)
+                    .is_err()
+                {
+                    continue;
+                }
+
+                let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+
+                if path.starts_with("/callback") {
+                    break (stream, path.to_string());
+                }
+
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                );
+            }
+            Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                thread::sleep(Duration::from_millis(100));
+            }
+            Err(e) => return Err(e.into()),
+        }
+    };
+
+    let url = Url::parse(&format!("http://127.0.0.1{}", path))?;
+
+    let mut code = None;
+    let mut returned_state = None;
+    let mut error = None;
+
+    for (key, value) in url.query_pairs() {
+        match key.as_ref() {
+            "code" => code = Some(value.into_owned()),
+            "state" => returned_state = Some(value.into_owned()),
+            "error" => error = Some(value.into_owned()),
+            _ => {}
+        }
+    }
+
+    let result = match (code, error) {
+        (_, Some(error)) => Err(anyhow!(t!("error-authorization-failed", error = error))),
+        (Some(_), _) if returned_state.as_deref() != Some(state) => {
+            Err(anyhow!(t!("error-authorization-state-mismatch")))
+        }
+        (Some(code), _) => Ok(code),
+        (None, None) => Err(anyhow!(t!("error-no-authorization-code"))),
+    };
+
+    let body = match result {
+        Ok(_) => t!("oauth-login-complete"),
+        Err(_) => t!("oauth-login-failed"),
+    };
+
+    let _ = write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    );
+
+    result
+}
+
+fn random_string(len: usize) -> String {
+    let mut bytes = vec![0u8; len];
+    rand::rng().fill_bytes(&mut bytes);
+
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Read, net::TcpStream, thread::JoinHandle};
+
+    use super::*;
+
+    fn token_server(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let url = format!("http://{}/token", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 1024];
+
+            let header_end = loop {
+                let read = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..read]);
+
+                if let Some(position) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                    break position + 4;
+                }
+            };
+
+            let headers = String::from_utf8_lossy(&request[..header_end]).to_lowercase();
+            let length: usize = headers
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .map(|length| length.trim().parse().unwrap())
+                .unwrap_or_default();
+
+            while request.len() < header_end + length {
+                let read = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+
+            String::from_utf8_lossy(&request[header_end..]).into_owned()
+        });
+
+        (url, handle)
+    }
+
+    fn settings(token_url: &str) -> OAuthSettings {
+        OAuthSettings {
+            token_url: token_url.to_string(),
+            authorization_url: "http://127.0.0.1/authorize".to_string(),
+            client_id: "movies".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn query_value(url: &str, key: &str) -> String {
+        Url::parse(url)
+            .unwrap()
+            .query_pairs()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.into_owned())
+            .unwrap()
+    }
+
+    fn send_callback(pending: &PendingAuthorization, query: &str) -> String {
+        let port = Url::parse(&pending.redirect_uri).unwrap().port().unwrap();
+
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        write!(
+            stream,
+            "GET /callback?{} HTTP/1.1\r\nHost: 127.0.0.1\r\n\r\n",
+            query
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        response
+    }
+
+    fn wait_for_result(pending: &PendingAuthorization) -> Result<String> {
+        let started = Instant::now();
+
+        loop {
+            if let Some(result) = pending.poll() {
+                return result;
+            }
+
+            assert!(started.elapsed() < Duration::from_secs(15));
+            thread::sleep(Duration::from_millis(20));
+        }
+    }
+
+    #[test]
+    fn client_credentials_requests_token() {
+        let (url, server) = token_server(
+            "200 OK",
+            r#"{"access_token":"access","refresh_token":"refresh","expires_in":60}"#,
+        );
+
+        let token = client_credentials(&Client::new(), &settings(&url)).unwrap();
+        let body = server.join().unwrap();
+
+        assert!(body.contains("grant_type=client_credentials"));
+        assert!(body.contains("client_id=movies"));
+
+        let mut creds = Credentials::default();
+        token.apply(&mut creds);
+
+        assert_eq!(creds.current_token, "access");
+        assert_eq!(creds.refresh_token, "refresh");
+        assert!(creds.token_expires_at > 0);
+    }
+
+    #[test]
+    fn password_sends_credentials() {
+        let (url, server) = token_server("200 OK", r#"{"access_token":"access"}"#);
+
+        let token = password(&Client::new(), &settings(&url), "alice", "secret").unwrap();
+        let body = server.join().unwrap();
+
+        assert!(body.contains("grant_type=password"));
+        assert!(body.contains("username=alice"));
+        assert!(body.contains("password=secret"));
+
+        let mut creds = Credentials::default();
+        token.apply(&mut creds);
+
+        assert_eq!(creds.current_token, "access");
+        assert_eq!(creds.token_expires_at, 0);
+    }
+
+    #[test]
+    fn authorization_code_exchange_uses_pkce() {
+        let mut settings = settings("");
+        let pending = begin_authorization_code(&settings).unwrap();
+
+        let state = query_value(&pending.url, "state");
+        let challenge = query_value(&pending.url, "code_challenge");
+
+        assert_eq!(query_value(&pending.url, "code_challenge_method"), "S256");
+        assert_eq!(
+            challenge,
+            URL_SAFE_NO_PAD.encode(Sha256::digest(pending.verifier.as_bytes()))
+        );
+
+        let response = send_callback(&pending, &format!("code=granted&state={}", state));
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert_eq!(wait_for_result(&pending).unwrap(), "granted");
+
+        let (url, server) = token_server("200 OK", r#"{"access_token":"access"}"#);
+        settings.token_url = url;
+
+        exchange_code(&Client::new(), &settings, &pending, "granted").unwrap();
+        let body = server.join().unwrap();
+
+        assert!(body.contains("grant_type=authorization_code"));
+        assert!(body.contains("code=granted"));
+        assert!(body.contains(&format!("code_verifier={}", pending.verifier)));
+    }
+
+    #[test]
+    fn authorization_code_rejects_state_mismatch() {
+        let pending = begin_authorization_code(&settings("")).unwrap();
+
+        send_callback(&pending, "code=granted&state=forged");
+
+        assert!(wait_for_result(&pending).is_err());
+    }
+
+    #[test]
+    fn authorization_code_ignores_silent_connections() {
+        let pending = begin_authorization_code(&settings("")).unwrap();
+        let state = query_value(&pending.url, "state");
+
+        let port = Url::parse(&pending.redirect_uri).unwrap().port().unwrap();
+        let _preconnect = TcpStream::connect(("127.0.0.1", port)).unwrap();
+
+        send_callback(&pending, &format!("code=granted&state={}", state));
+
+        assert_eq!(wait_for_result(&pending).unwrap(), "granted");
+    }
+
+    #[test]
+    fn cancelling_authorization_releases_port() {
+        let pending = begin_authorization_code(&settings("")).unwrap();
+        let port = Url::parse(&pending.redirect_uri).unwrap().port().unwrap();
+
+        drop(pending);
+
+        let started = Instant::now();
+
+        while TcpListener::bind(("127.0.0.1", port)).is_err() {
+            assert!(started.elapsed() < Duration::from_secs(2));
+            thread::sleep(Duration::from_millis(20));
+        }
+    }
+
+    #[test]
+    fn refresh_replaces_access_token() {
+        let (url, server) = token_server(
+            "200 OK",
+            r#"{"access_token":"renewed","refresh_token":"rotated","expires_in":60}"#,
+        );
+
+        let token = refresh(&Client::new(), &settings(&url), "refresh").unwrap();
+        let body = server.join().unwrap();
+
+        assert!(body.contains("grant_type=refresh_token"));
+        assert!(body.contains("refresh_token=refresh"));
+
+        let mut creds = Credentials::default();
+        token.apply(&mut creds);
+
+        assert_eq!(creds.current_token, "renewed");
+        assert_eq!(creds.refresh_token, "rotated");
+    }
+
+    #[test]
+    fn refresh_rejection_is_detected() {
+        let (url, server) = token_server("400 Bad Request", r#"{"error":"invalid_grant"}"#);
+
+        let error = refresh(&Client::new(), &settings(&url), "expired")
+            .err()
+            .unwrap();
+        server.join().unwrap();
+
+        assert!(is_rejected_grant(&error));
+    }
+
+    #[test]
+    fn refresh_transient_failures_are_not_rejections() {
+        let (url, server) = token_server("503 Service Unavailable", "{}");
+
+        let error = refresh(&Client::new(), &settings(&url), "refresh")
+            .err()
+            .unwrap();
+        server.join().unwrap();
+
+        assert!(!is_rejected_grant(&error));
+
+        let port = TcpListener::bind(("127.0.0.1", 0))
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let url = format!("http://127.0.0.1:{}/token", port);
+
+        let error = refresh(&Client::new(), &settings(&url), "refresh")
+            .err()
+            .unwrap();
+
+        assert!(!is_rejected_grant(&error));
+    }
+}
@@ -0,0 +1,362 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fs, mem,
+    path::{Path, PathBuf},
+    sync::{
+        Arc,
+        mpsc::{self, Receiver, Sender},
+    },
+    thread,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Result, anyhow};
+use reqwest::{
+    StatusCode,
+    blocking::RequestBuilder,
+    header::{ETAG, IF_NONE_MATCH},
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    communication_layer::{DataLayer, live::Notifier},
+    t,
+};
+
+const MAX_CACHE_BYTES: u64 = 64 * 1024 * 1024;
+const MAX_IN_FLIGHT: usize = 4;
+const MAX_IN_MEMORY: usize = 256;
+
+static UNAVAILABLE: Poster = Poster::Unavailable;
+
+pub enum Poster {
+    Loading,
+    Ready { uri: String, bytes: Arc<[u8]> },
+    Unavailable,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct CacheEntry {
+    etag: Option<String>,
+    size: u64,
+    last_used: u64,
+}
+
+enum Fetched {
+    NotModified,
+    Data {
+        etag: Option<String>,
+        bytes: Vec<u8>,
+    },
+}
+
+struct Job {
+    url: String,
+    request: RequestBuilder,
+}
+
+type FetchResult = (String, Result<Fetched>);
+
+pub struct PosterCache {
+    directory: PathBuf,
+    index: HashMap<String, CacheEntry>,
+    posters: HashMap<String, Poster>,
+    last_shown: HashMap<String, u64>,
+    released: Vec<String>,
+    index_changed: bool,
+    frame: u64,
+    queue: VecDeque<Job>,
+    in_flight: usize,
+    sender: Sender<FetchResult>,
+    receiver: Receiver<FetchResult>,
+    notifier: Option<Notifier>,
+    version: u64,
+}
+
+fn cache_key(url: &str) -> String {
+    format!("{:x}", Sha256::digest(url.as_bytes()))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+fn fetch(request: RequestBuilder) -> Result<Fetched> {
+    let response = request.send()?;
+
+    match response.status() {
+        StatusCode::NOT_MODIFIED => Ok(Fetched::NotModified),
+        status if status.is_success() => {
+            let etag = response
+                .headers()
+                .get(ETAG)
+                .and_then(|etag| etag.to_str().ok())
+                .map(String::from);
+
+            Ok(Fetched::Data {
+                etag,
+                bytes: response.bytes()?.to_vec(),
+            })
+        }
+        status => Err(anyhow!(t!(
+            "error-load-poster",
+            status = status.to_string()
+        ))),
+    }
+}
+
+impl PosterCache {
+    pub fn new() -> Self {
+        let directory = fs::canonicalize("./").unwrap().join("poster_cache");
+
+        let index = fs::read_to_string(directory.join("index.json"))
+            .ok()
+            .and_then(|index| serde_json::from_str(&index).ok())
+            .unwrap_or_default();
+
+        let (sender, receiver) = mpsc::channel();
+
+        Self {
+            directory,
+            index,
+            posters: HashMap::new(),
+            last_shown: HashMap::new(),
+            released: Vec::new(),
+            index_changed: false,
+            frame: 0,
+            queue: VecDeque::new(),
+            in_flight: 0,
+            sender,
+            receiver,
+            notifier: None,
+            version: 0,
+        }
+    }
+
+    pub fn set_notifier(&mut self, notifier: Notifier) {
+        self.notifier = Some(notifier);
+    }
+
+    pub fn get(&mut self, data_layer: &DataLayer, poster_url: &str) -> &Poster {
+        let url = data_layer.resolve_url(poster_url);
+
+        let Ok(client) = data_layer.client() else {
+            return &UNAVAILABLE;
+        };
+
+        self.last_shown.insert(url.clone(), self.frame);
+
+        if !self.posters.contains_key(&url) {
+            let key = cache_key(&url);
+
+            let cached = self.index.get(&key).cloned().and_then(|entry| {
+                fs::read(self.file_path(&key))
+                    .ok()
+                    .map(|bytes| (entry, bytes))
+            });
+
+            let mut request = client.get(&url);
+
+            if data_layer.is_same_origin(&url) {
+                request = data_layer.with_auth(request);
+            }
+
+            let poster = match cached {
+                Some((entry, bytes)) => {
+                    if let Some(etag) = &entry.etag {
+                        request = request.header(IF_NONE_MATCH, etag);
+                    }
+
+                    if let Some(entry) = self.index.get_mut(&key) {
+                        entry.last_used = now();
+                        self.index_changed = true;
+                    }
+
+                    self.ready(&key, bytes)
+                }
+                None => Poster::Loading,
+            };
+
+            self.replace(&url, poster);
+            self.queue.push_back(Job {
+                url: url.clone(),
+                request,
+            });
+            self.start_jobs();
+        }
+
+        &self.posters[&url]
+    }
+
+    pub fn invalidate(&mut self, data_layer: &DataLayer, poster_url: &str) {
+        let url = data_layer.resolve_url(poster_url);
+        let key = cache_key(&url);
+
+        self.release(&url);
+
+        if self.index.remove(&key).is_some() {
+            let _ = fs::remove_file(self.file_path(&key));
+            self.store_index();
+        }
+    }
+
+    pub fn poll(&mut self) {
+        let results: Vec<FetchResult> = self.receiver.try_iter().collect();
+
+        for (url, result) in results {
+            self.in_flight -= 1;
+
+            match result {
+                Ok(Fetched::NotModified) => {}
+                Ok(Fetched::Data { etag, bytes }) => {
+                    let key = self.store(&url, etag, &bytes);
+
+                    if self.posters.contains_key(&url) {
+                        let poster = self.ready(&key, bytes);
+                        self.replace(&url, poster);
+                    }
+                }
+                Err(_) => {
+                    if let Some(poster) = self.posters.get_mut(&url)
+                        && matches!(poster, Poster::Loading)
+                    {
+                        *poster = Poster::Unavailable;
+                    }
+                }
+            }
+        }
+
+        self.start_jobs();
+        self.forget_unused();
+
+        if self.index_changed {
+            self.index_changed = false;
+            self.store_index();
+        }
+
+        self.frame += 1;
+    }
+
+    pub fn take_released(&mut self) -> Vec<String> {
+        mem::take(&mut self.released)
+    }
+
+    fn replace(&mut self, url: &str, poster: Poster) {
+        if let Some(Poster::Ready { uri, .. }) = self.posters.insert(url.to_string(), poster) {
+            self.released.push(uri);
+        }
+    }
+
+    fn release(&mut self, url: &str) {
+        self.last_shown.remove(url);
+
+        if let Some(Poster::Ready { uri, .. }) = self.posters.remove(url) {
+            self.released.push(uri);
+        }
+    }
+
+    fn forget_unused(&mut self) {
+        while self.posters.len() > MAX_IN_MEMORY {
+            let Some(oldest) = self
+                .posters
+                .keys()
+                .filter(|url| !self.queue.iter().any(|job| &job.url == *url))
+                .min_by_key(|url| self.last_shown.get(*url).copied().unwrap_or_default())
+                .cloned()
+            else {
+                break;
+            };
+
+            if self.last_shown.get(&oldest) == Some(&self.frame) {
+                break;
+            }
+
+            self.release(&oldest);
+        }
+    }
+
+    fn start_jobs(&mut self) {
+        while self.in_flight < MAX_IN_FLIGHT
+            && let Some(job) = self.queue.pop_front()
+        {
+            let sender = self.sender.clone();
+            let notifier = self.notifier.clone();
+
+            self.in_flight += 1;
+
+            thread::spawn(move || {
+                let _ = sender.send((job.url, fetch(job.request)));
+
+                if let Some(notifier) = notifier {
+                    notifier();
+                }
+            });
+        }
+    }
+
+    fn ready(&mut self, key: &str, bytes: Vec<u8>) -> Poster {
+        self.version += 1;
+
+        Poster::Ready {
+            uri: format!("bytes://poster/{}-{}", key, self.version),
+            bytes: bytes.into(),
+        }
+    }
+
+    fn file_path(&self, key: &str) -> PathBuf {
+        self.directory.join(Path::new(key))
+    }
+
+    fn store(&mut self, url: &str, etag: Option<String>, bytes: &[u8]) -> String {
+        let key = cache_key(url);
+
+        if fs::create_dir_all(&self.directory).is_ok()
+            && fs::write(self.file_path(&key), bytes).is_ok()
+        {
+            self.index.insert(
+                key.clone(),
+                CacheEntry {
+                    etag,
+                    size: bytes.len() as u64,
+                    last_used: now(),
+                },
+            );
+
+            self.evict(&key);
+            self.store_index();
+        }
+
+        key
+    }
+
+    fn evict(&mut self, keep: &str) {
+        let mut total: u64 = self.index.values().map(|entry| entry.size).sum();
+
+        while total > MAX_CACHE_BYTES {
+            let Some(oldest) = self
+                .index
+                .iter()
+                .filter(|(key, _)| key.as_str() != keep)
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+
+            if let Some(entry) = self.index.remove(&oldest) {
+                total -= entry.size;
+                let _ = fs::remove_file(self.file_path(&oldest));
+            }
+        }
+    }
+
+    fn store_index(&self) {
+        if let Ok(index) = serde_json::to_string(&self.index) {
+            let _ = fs::write(self.directory.join("index.json"), index);
+        }
+    }
+}
@@ -1,12 +1,51 @@
+use cookie_store::CookieStore;
+use reqwest::Url;
 use serde::{Deserialize, Serialize};
-use std::{fs, path::Path};
+use std::{
+    fs,
+    io::BufReader,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
-use crate::data_types::{SessionState, Credentials};
+use crate::data_types::{
+    AppearanceSettings, AuthMode, AutoRefreshSettings, Credentials, IdentitySettings, KeyBindings,
+    Language, LiveUpdateSettings, OAuthFlow, OAuthSettings, PasswordPolicy, ProxySettings,
+    SavedSearch, SessionState, TlsSettings, UserEndpoints,
+};
 
-#[derive(Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default, Clone)]
 pub struct Config {
     pub base_url: String,
     pub creds: Credentials,
+    #[serde(default)]
+    pub auth_mode: AuthMode,
+    #[serde(default)]
+    pub session_cookie: String,
+    #[serde(default)]
+    pub oauth: OAuthSettings,
+    #[serde(default)]
+    pub identity: IdentitySettings,
+    #[serde(default)]
+    pub user_endpoints: UserEndpoints,
+    #[serde(default)]
+    pub password_policy: PasswordPolicy,
+    #[serde(default)]
+    pub tls: TlsSettings,
+    #[serde(default)]
+    pub proxy: ProxySettings,
+    #[serde(default)]
+    pub auto_refresh: AutoRefreshSettings,
+    #[serde(default)]
+    pub live_updates: LiveUpdateSettings,
+    #[serde(default)]
+    pub saved_searches: Vec<SavedSearch>,
+    #[serde(default)]
+    pub key_bindings: KeyBindings,
+    #[serde(default)]
+    pub appearance: AppearanceSettings,
+    #[serde(default)]
+    pub language: Language,
 }
 
 impl Config {
@@ -31,11 +70,70 @@ impl Config {
         fs::write(cwd.join(Path::new("config.json")), config).expect("Failed to write config file");
     }
 
-    pub fn current_session_state(&self) -> SessionState {
-        if self.creds.current_token.is_empty() {
-            SessionState::Unauthenticated
-        } else {
+    pub fn load_cookies() -> CookieStore {
+        let Ok(cwd) = fs::canonicalize("./") else {
+            return CookieStore::default();
+        };
+
+        match fs::File::open(cwd.join(Path::new("cookies.json"))) {
+            Ok(file) => {
+                cookie_store::serde::json::load_all(BufReader::new(file)).unwrap_or_default()
+            }
+            Err(_) => CookieStore::default(),
+        }
+    }
+
+    pub fn store_cookies(&self, cookies: &CookieStore) {
+        if self.auth_mode != AuthMode::CookieSession {
+            return;
+        }
+
+        let Ok(cwd) = fs::canonicalize("./") else {
+            return;
+        };
+
+        if let Ok(mut file) = fs::File::create(cwd.join(Path::new("cookies.json"))) {
+            let _ =
+                cookie_store::serde::json::save_incl_expired_and_nonpersistent(cookies, &mut file);
+        }
+    }
+
+    pub fn login_requires_password(&self) -> bool {
+        self.auth_mode != AuthMode::OAuth || self.oauth.flow == OAuthFlow::Password
+    }
+
+    pub fn token_expired(&self) -> bool {
+        if self.creds.token_expires_at == 0 {
+            return false;
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        now >= self.creds.token_expires_at
+    }
+
+    pub fn current_session_state(&self, cookies: &CookieStore) -> SessionState {
+        let authenticated = match self.auth_mode {
+            AuthMode::Token => !self.creds.current_token.is_empty(),
+            AuthMode::OAuth => {
+                !self.creds.current_token.is_empty()
+                    && (!self.token_expired() || !self.creds.refresh_token.is_empty())
+            }
+            AuthMode::CookieSession => match Url::parse(&self.base_url) {
+                Ok(url) => cookies.matches(&url).iter().any(|cookie| {
+                    self.session_cookie.is_empty() || cookie.name() == self.session_cookie
+                }),
+                Err(_) => false,
+            },
+        };
+
+        if authenticated {
             SessionState::Authenticated
+        } else {
+            SessionState::Unauthenticated
         }
     }
 }
@@ -1,21 +1,305 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+use crate::t;
 
 #[derive(Serialize, Deserialize, Default, Clone)]
 pub struct Credentials {
     pub username: String,
     pub password: String,
     pub current_token: String,
+    #[serde(default)]
+    pub refresh_token: String,
+    #[serde(default)]
+    pub token_expires_at: u64,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AuthMode {
+    #[default]
+    Token,
+    CookieSession,
+    OAuth,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OAuthFlow {
+    #[default]
+    ClientCredentials,
+    Password,
+    AuthorizationCode,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(default)]
+pub struct OAuthSettings {
+    pub flow: OAuthFlow,
+    pub discovery_url: String,
+    pub authorization_url: String,
+    pub token_url: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub scope: String,
+    pub redirect_port: u16,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TlsVersion {
+    #[default]
+    Default,
+    Tls1_0,
+    Tls1_1,
+    #[serde(alias = "Tls1_3")]
+    Tls1_2,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
+pub enum ClientIdentity {
+    #[default]
+    None,
+    Pem {
+        cert_path: String,
+        key_path: String,
+    },
+    Pkcs12 {
+        path: String,
+        password: String,
+    },
+}
+
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(default)]
+pub struct TlsSettings {
+    pub root_ca_paths: Vec<String>,
+    pub client_identity: ClientIdentity,
+    pub min_version: TlsVersion,
+    pub accept_invalid_certs: bool,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LiveTransport {
+    #[default]
+    ServerSentEvents,
+    WebSocket,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct LiveUpdateSettings {
+    pub enabled: bool,
+    pub transport: LiveTransport,
+    pub endpoint: String,
+    pub reconnect_delay_secs: u64,
+    pub poll_interval_secs: u64,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub enum LiveState {
+    #[default]
+    Off,
+    Connecting,
+    Connected,
+    Polling,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
+pub struct SavedSearch {
+    pub name: String,
+    pub query: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct AutoRefreshSettings {
+    pub enabled: bool,
+    pub interval_secs: u64,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SortColumn {
+    #[default]
+    Title,
+    Description,
+    ReleaseYear,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct MovieTableSettings {
+    pub sort_column: Option<SortColumn>,
+    pub sort_ascending: bool,
+    pub title_width: f32,
+    pub description_width: f32,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    #[default]
+    System,
+    English,
+    German,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeMode {
+    Light,
+    Dark,
+    #[default]
+    System,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AccentColor {
+    #[default]
+    Default,
+    Blue,
+    Green,
+    Purple,
+    Orange,
+    Pink,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct AppearanceSettings {
+    pub theme: ThemeMode,
+    pub accent: AccentColor,
+    pub ui_scale: f32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Command {
+    OpenPalette,
+    CreateMovie,
+    ListMovies,
+    UpdateMovie,
+    DeleteMovie,
+    ClearSelection,
+    SelectPrevious,
+    SelectNext,
+    FocusSearch,
+    Register,
+    Login,
+    Logout,
+    ChangePassword,
+    DeleteAccount,
+    ManageUsers,
+    ProxySettings,
+    LiveUpdates,
+    Appearance,
+    ToggleTheme,
+    KeyboardShortcuts,
+    ToggleNotifications,
+    ResetLayout,
 }
 
 #[derive(Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct KeyBindings {
+    overrides: BTreeMap<Command, String>,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
+#[serde(default)]
+pub struct LayoutState {
+    pub movie_table: MovieTableSettings,
+    pub users_panel_open: bool,
+    pub notifications_open: bool,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct ProxySettings {
+    pub http_url: String,
+    pub https_url: String,
+    pub socks5_url: String,
+    pub username: String,
+    pub password: String,
+    pub no_proxy: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    pub require_lowercase: bool,
+    pub require_uppercase: bool,
+    pub require_digit: bool,
+    pub require_symbol: bool,
+    pub forbid_username: bool,
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct FieldErrors {
+    errors: BTreeMap<String, String>,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
+#[serde(from = "RawMovie")]
 pub struct Movie {
     pub id: i32,
     pub title: String,
     pub description: String,
     pub release_year: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub genres: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub director: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rating: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runtime: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub poster_url: Option<String>,
+    #[serde(flatten)]
+    pub extras: Map<String, Value>,
+}
+
+#[derive(Deserialize)]
+struct RawMovie {
+    id: i32,
+    title: String,
+    description: String,
+    release_year: i32,
+    #[serde(default)]
+    genres: Option<Value>,
+    #[serde(default)]
+    director: Option<Value>,
+    #[serde(default)]
+    rating: Option<Value>,
+    #[serde(default)]
+    runtime: Option<Value>,
+    #[serde(default)]
+    poster_url: Option<Value>,
+    #[serde(flatten)]
+    extras: Map<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
+pub struct User {
+    #[serde(default)]
+    pub id: i32,
+    pub username: String,
+    #[serde(default)]
+    pub role: String,
+    #[serde(default)]
+    pub disabled: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct UserEndpoints {
+    pub list: String,
+    pub change_role: String,
+    pub reset_password: String,
+    pub disable: String,
+    pub delete: String,
+    pub change_own_password: String,
+    pub delete_own_account: String,
 }
 
-#[derive(Serialize, Deserialize, Default, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
 pub enum Role {
     #[default]
     None,
@@ -23,19 +307,481 @@ pub enum Role {
     User,
 }
 
-#[derive(Serialize, Deserialize, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    CreateMovie,
+    UpdateMovie,
+    DeleteMovie,
+    RegisterAdmin,
+    ManageUsers,
+}
+
+#[derive(Clone)]
+pub struct UserIdentity {
+    pub username: String,
+    pub role: Role,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+pub enum IdentitySource {
+    #[default]
+    Endpoint,
+    TokenClaims,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct IdentitySettings {
+    pub source: IdentitySource,
+    pub endpoint: String,
+    pub role_claim: String,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
 pub enum SessionState {
     #[default]
     Unauthenticated,
     Authenticated,
 }
 
+fn lenient_field<T: DeserializeOwned>(
+    extras: &mut Map<String, Value>,
+    key: &str,
+    value: Option<Value>,
+) -> Option<T> {
+    let value = value?;
+
+    match serde_json::from_value(value.clone()) {
+        Ok(value) => Some(value),
+        Err(_) => {
+            extras.insert(key.to_string(), value);
+            None
+        }
+    }
+}
+
+impl From<RawMovie> for Movie {
+    fn from(raw: RawMovie) -> Self {
+        let mut extras = raw.extras;
+
+        Self {
+            id: raw.id,
+            title: raw.title,
+            description: raw.description,
+            release_year: raw.release_year,
+            genres: lenient_field(&mut extras, "genres", raw.genres),
+            director: lenient_field(&mut extras, "director", raw.director),
+            rating: lenient_field(&mut extras, "rating", raw.rating),
+            runtime: lenient_field(&mut extras, "runtime", raw.runtime),
+            poster_url: lenient_field(&mut extras, "poster_url", raw.poster_url),
+            extras,
+        }
+    }
+}
+
 impl From<String> for Role {
     fn from(role: String) -> Self {
         match role {
-            _ if role == "Admin" => Role::Admin,
-            _ if role == "User" => Role::User,
+            _ if role.eq_ignore_ascii_case("Admin") => Role::Admin,
+            _ if role.eq_ignore_ascii_case("User") => Role::User,
             _ => Role::default(),
         }
     }
 }
+
+impl Role {
+    pub fn allows(&self, permission: Permission) -> bool {
+        match self {
+            Role::Admin => true,
+            Role::User => !matches!(
+                permission,
+                Permission::DeleteMovie | Permission::RegisterAdmin | Permission::ManageUsers
+            ),
+            Role::None => false,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            Role::Admin => t!("role-admin"),
+            Role::User => t!("role-user"),
+            Role::None => t!("role-none"),
+        }
+    }
+
+    pub fn api_name(&self) -> &str {
+        match self {
+            Role::Admin => "admin",
+            Role::User => "user",
+            Role::None => "",
+        }
+    }
+}
+
+impl Default for UserEndpoints {
+    fn default() -> Self {
+        Self {
+            list: "/user/list".to_string(),
+            change_role: "/user/update/{id}/role".to_string(),
+            reset_password: "/user/update/{id}/password".to_string(),
+            disable: "/user/update/{id}/disable".to_string(),
+            delete: "/user/delete/{id}".to_string(),
+            change_own_password: "/user/password".to_string(),
+            delete_own_account: "/user/delete".to_string(),
+        }
+    }
+}
+
+impl FieldErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, field: &str, message: impl Into<String>) {
+        self.errors
+            .entry(field.to_string())
+            .or_insert_with(|| message.into());
+    }
+
+    pub fn require(&mut self, field: &str, condition: bool, message: impl Into<String>) {
+        if !condition {
+            self.add(field, message);
+        }
+    }
+
+    pub fn get(&self, field: &str) -> Option<&str> {
+        self.errors.get(field).map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn any_of(&self, fields: &[&str]) -> bool {
+        fields.iter().any(|field| self.errors.contains_key(*field))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.errors
+            .iter()
+            .map(|(field, message)| (field.as_str(), message.as_str()))
+    }
+}
+
+impl PasswordPolicy {
+    pub fn violations(&self, password: &str, username: &str) -> Vec<String> {
+        let mut violations = Vec::new();
+
+        if password.chars().count() < self.min_length {
+            violations.push(t!("password-min-length", count = self.min_length));
+        }
+
+        if self.require_lowercase && !password.chars().any(char::is_lowercase) {
+            violations.push(t!("password-add-lowercase"));
+        }
+
+        if self.require_uppercase && !password.chars().any(char::is_uppercase) {
+            violations.push(t!("password-add-uppercase"));
+        }
+
+        if self.require_digit && !password.chars().any(|c| c.is_ascii_digit()) {
+            violations.push(t!("password-add-digit"));
+        }
+
+        if self.require_symbol && !password.chars().any(|c| !c.is_alphanumeric()) {
+            violations.push(t!("password-add-symbol"));
+        }
+
+        if self.forbid_username
+            && !username.is_empty()
+            && password.to_lowercase().contains(&username.to_lowercase())
+        {
+            violations.push(t!("password-no-username"));
+        }
+
+        violations
+    }
+
+    pub fn strength(password: &str) -> u8 {
+        let classes = [
+            password.chars().any(char::is_lowercase),
+            password.chars().any(char::is_uppercase),
+            password.chars().any(|c| c.is_ascii_digit()),
+            password.chars().any(|c| !c.is_alphanumeric()),
+        ]
+        .iter()
+        .filter(|present| **present)
+        .count();
+
+        let length = password.chars().count();
+
+        let score = match length {
+            0 => return 0,
+            1..8 => 0,
+            8..12 => 1,
+            12..16 => 2,
+            _ => 3,
+        } + classes.saturating_sub(1);
+
+        score.min(4) as u8
+    }
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: 8,
+            require_lowercase: true,
+            require_uppercase: true,
+            require_digit: true,
+            require_symbol: false,
+            forbid_username: true,
+        }
+    }
+}
+
+impl Default for LiveUpdateSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            transport: LiveTransport::ServerSentEvents,
+            endpoint: String::from("/movie/events"),
+            reconnect_delay_secs: 5,
+            poll_interval_secs: 30,
+        }
+    }
+}
+
+impl LiveTransport {
+    pub fn name(&self) -> String {
+        match self {
+            LiveTransport::ServerSentEvents => t!("live-transport-sse"),
+            LiveTransport::WebSocket => t!("live-transport-websocket"),
+        }
+    }
+}
+
+impl LiveState {
+    pub fn name(&self) -> String {
+        match self {
+            LiveState::Off => t!("live-state-off"),
+            LiveState::Connecting => t!("live-state-connecting"),
+            LiveState::Connected => t!("live-state-connected"),
+            LiveState::Polling => t!("live-state-polling"),
+        }
+    }
+}
+
+impl Command {
+    pub const ALL: [Command; 22] = [
+        Command::OpenPalette,
+        Command::CreateMovie,
+        Command::ListMovies,
+        Command::UpdateMovie,
+        Command::DeleteMovie,
+        Command::ClearSelection,
+        Command::SelectPrevious,
+        Command::SelectNext,
+        Command::FocusSearch,
+        Command::Register,
+        Command::Login,
+        Command::Logout,
+        Command::ChangePassword,
+        Command::DeleteAccount,
+        Command::ManageUsers,
+        Command::ProxySettings,
+        Command::LiveUpdates,
+        Command::Appearance,
+        Command::ToggleTheme,
+        Command::KeyboardShortcuts,
+        Command::ToggleNotifications,
+        Command::ResetLayout,
+    ];
+
+    pub fn name(&self) -> String {
+        match self {
+            Command::OpenPalette => t!("command-open-palette"),
+            Command::CreateMovie => t!("command-create-movie"),
+            Command::ListMovies => t!("command-list-movies"),
+            Command::UpdateMovie => t!("command-update-movie"),
+            Command::DeleteMovie => t!("command-delete-movie"),
+            Command::ClearSelection => t!("command-clear-selection"),
+            Command::SelectPrevious => t!("command-select-previous"),
+            Command::SelectNext => t!("command-select-next"),
+            Command::FocusSearch => t!("command-focus-search"),
+            Command::Register => t!("command-register"),
+            Command::Login => t!("command-login"),
+            Command::Logout => t!("command-logout"),
+            Command::ChangePassword => t!("command-change-password"),
+            Command::DeleteAccount => t!("command-delete-account"),
+            Command::ManageUsers => t!("command-manage-users"),
+            Command::ProxySettings => t!("command-proxy-settings"),
+            Command::LiveUpdates => t!("command-live-updates"),
+            Command::Appearance => t!("command-appearance"),
+            Command::ToggleTheme => t!("command-toggle-theme"),
+            Command::KeyboardShortcuts => t!("command-keyboard-shortcuts"),
+            Command::ToggleNotifications => t!("command-toggle-notifications"),
+            Command::ResetLayout => t!("command-reset-layout"),
+        }
+    }
+
+    pub fn default_shortcut(&self) -> &'static str {
+        match self {
+            Command::OpenPalette => "Ctrl+Shift+P",
+            Command::CreateMovie => "Ctrl+N",
+            Command::ListMovies => "F5",
+            Command::UpdateMovie => "Enter",
+            Command::DeleteMovie => "Delete",
+            Command::ClearSelection => "Escape",
+            Command::SelectPrevious => "ArrowUp",
+            Command::SelectNext => "ArrowDown",
+            Command::FocusSearch => "Ctrl+F",
+            _ => "",
+        }
+    }
+}
+
+impl KeyBindings {
+    pub fn get(&self, command: Command) -> &str {
+        self.overrides
+            .get(&command)
+            .map(String::as_str)
+            .unwrap_or(command.default_shortcut())
+    }
+
+    pub fn set(&mut self, command: Command, shortcut: String) {
+        if shortcut == command.default_shortcut() {
+            self.overrides.remove(&command);
+        } else {
+            self.overrides.insert(command, shortcut);
+        }
+    }
+}
+
+impl Language {
+    pub const ALL: [Language; 3] = [Language::System, Language::English, Language::German];
+
+    pub fn name(&self) -> String {
+        match self {
+            Language::System => t!("language-system"),
+            Language::English => String::from("English"),
+            Language::German => String::from("Deutsch"),
+        }
+    }
+}
+
+impl ThemeMode {
+    pub fn name(&self) -> String {
+        match self {
+            ThemeMode::Light => t!("theme-light"),
+            ThemeMode::Dark => t!("theme-dark"),
+            ThemeMode::System => t!("theme-system"),
+        }
+    }
+}
+
+impl AccentColor {
+    pub const ALL: [AccentColor; 6] = [
+        AccentColor::Default,
+        AccentColor::Blue,
+        AccentColor::Green,
+        AccentColor::Purple,
+        AccentColor::Orange,
+        AccentColor::Pink,
+    ];
+
+    pub fn name(&self) -> String {
+        match self {
+            AccentColor::Default => t!("accent-default"),
+            AccentColor::Blue => t!("accent-blue"),
+            AccentColor::Green => t!("accent-green"),
+            AccentColor::Purple => t!("accent-purple"),
+            AccentColor::Orange => t!("accent-orange"),
+            AccentColor::Pink => t!("accent-pink"),
+        }
+    }
+
+    pub fn rgb(&self) -> Option<[u8; 3]> {
+        match self {
+            AccentColor::Default => None,
+            AccentColor::Blue => Some([40, 110, 220]),
+            AccentColor::Green => Some([40, 160, 90]),
+            AccentColor::Purple => Some([135, 80, 210]),
+            AccentColor::Orange => Some([230, 130, 30]),
+            AccentColor::Pink => Some([215, 70, 140]),
+        }
+    }
+}
+
+impl Default for AppearanceSettings {
+    fn default() -> Self {
+        Self {
+            theme: ThemeMode::System,
+            accent: AccentColor::Default,
+            ui_scale: 1.0,
+        }
+    }
+}
+
+impl Default for MovieTableSettings {
+    fn default() -> Self {
+        Self {
+            sort_column: None,
+            sort_ascending: true,
+            title_width: 150.0,
+            description_width: 200.0,
+        }
+    }
+}
+
+impl Default for AutoRefreshSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_secs: 30,
+        }
+    }
+}
+
+impl Default for IdentitySettings {
+    fn default() -> Self {
+        Self {
+            source: IdentitySource::default(),
+            endpoint: "/me".to_string(),
+            role_claim: "role".to_string(),
+        }
+    }
+}
+
+impl TlsSettings {
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+
+        if self.root_ca_paths.is_empty() {
+            parts.push(t!("tls-system-trust"));
+        } else {
+            parts.push(t!("tls-extra-cas", count = self.root_ca_paths.len()));
+        }
+
+        match self.client_identity {
+            ClientIdentity::None => {}
+            ClientIdentity::Pem { .. } => parts.push(t!("tls-client-cert", format = "PEM")),
+            ClientIdentity::Pkcs12 { .. } => parts.push(t!("tls-client-cert", format = "PKCS#12")),
+        }
+
+        match self.min_version {
+            TlsVersion::Default => {}
+            TlsVersion::Tls1_0 => parts.push(t!("tls-min-version", version = "1.0")),
+            TlsVersion::Tls1_1 => parts.push(t!("tls-min-version", version = "1.1")),
+            TlsVersion::Tls1_2 => parts.push(t!("tls-min-version", version = "1.2")),
+        }
+
+        parts.join(", ")
+    }
+}
+
+impl ProxySettings {
+    pub fn is_empty(&self) -> bool {
+        self.http_url.is_empty() && self.https_url.is_empty() && self.socks5_url.is_empty()
+    }
+}
@@ -0,0 +1,204 @@
+use std::{
+    env,
+    sync::{LazyLock, RwLock},
+};
+
+use fluent_bundle::{FluentResource, FluentValue, concurrent::FluentBundle};
+use unic_langid::LanguageIdentifier;
+
+use crate::data_types::Language;
+
+pub use fluent_bundle::FluentArgs;
+
+struct Catalog {
+    language: Language,
+    bundle: FluentBundle<FluentResource>,
+}
+
+static CATALOGS: LazyLock<Vec<Catalog>> = LazyLock::new(|| {
+    vec![
+        Catalog::new(Language::English, include_str!("../../locales/en.ftl")),
+        Catalog::new(Language::German, include_str!("../../locales/de.ftl")),
+    ]
+});
+
+static CURRENT: RwLock<Language> = RwLock::new(Language::English);
+
+#[macro_export]
+macro_rules! t {
+    ($id:literal) => {
+        $crate::i18n::translate($id, None)
+    };
+    ($id:literal, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = $crate::i18n::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)+
+
+        $crate::i18n::translate($id, Some(&args))
+    }};
+}
+
+fn separators(language: Language) -> (char, char) {
+    match language {
+        Language::German => (',', '.'),
+        _ => ('.', ','),
+    }
+}
+
+pub fn format_number(value: f64, decimals: usize) -> String {
+    format_number_for(current(), value, decimals)
+}
+
+pub fn parse_number(text: &str) -> Option<f64> {
+    let (decimal, group) = separators(current());
+
+    if text.contains(group) {
+        return None;
+    }
+
+    text.trim().replace(decimal, ".").parse().ok()
+}
+
+fn format_number_for(language: Language, value: f64, decimals: usize) -> String {
+    let (decimal, group) = separators(language);
+
+    let text = format!("{:.*}", decimals, value.abs());
+    let (integer, fraction) = match text.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (text.as_str(), None),
+    };
+
+    let mut grouped = String::new();
+
+    for (position, c) in integer.chars().enumerate() {
+        if position > 0 && (integer.len() - position) % 3 == 0 {
+            grouped.push(group);
+        }
+
+        grouped.push(c);
+    }
+
+    if value < 0.0 && text.chars().any(|c| c.is_ascii_digit() && c != '0') {
+        grouped.insert(0, '-');
+    }
+
+    if let Some(fraction) = fraction {
+        grouped.push(decimal);
+        grouped.push_str(fraction);
+    }
+
+    grouped
+}
+
+fn format_value(language: Language, value: &FluentValue) -> Option<String> {
+    let FluentValue::Number(number) = value else {
+        return None;
+    };
+
+    let natural = number
+        .value
+        .to_string()
+        .split_once('.')
+        .map(|(_, fraction)| fraction.len().min(6))
+        .unwrap_or(0);
+
+    let decimals = natural
+        .max(number.options.minimum_fraction_digits.unwrap_or(0))
+        .min(number.options.maximum_fraction_digits.unwrap_or(usize::MAX));
+
+    Some(format_number_for(language, number.value, decimals))
+}
+
+fn format_english<M>(value: &FluentValue, _: &M) -> Option<String> {
+    format_value(Language::English, value)
+}
+
+fn format_german<M>(value: &FluentValue, _: &M) -> Option<String> {
+    format_value(Language::German, value)
+}
+
+impl Catalog {
+    fn new(language: Language, source: &str) -> Self {
+        let resource =
+            FluentResource::try_new(source.to_string()).unwrap_or_else(|(resource, _)| resource);
+
+        let mut bundle = FluentBundle::new_concurrent(vec![language.identifier()]);
+        bundle.set_use_isolating(false);
+        bundle.set_formatter(Some(match language {
+            Language::German => format_german,
+            _ => format_english,
+        }));
+
+        let _ = bundle.add_builtins();
+        let _ = bundle.add_resource(resource);
+
+        Self { language, bundle }
+    }
+
+    fn format(&self, id: &str, args: Option<&FluentArgs>) -> Option<String> {
+        let pattern = self.bundle.get_message(id)?.value()?;
+        let mut errors = Vec::new();
+
+        Some(
+            self.bundle
+                .format_pattern(pattern, args, &mut errors)
+                .into_owned(),
+        )
+    }
+}
+
+impl Language {
+    fn identifier(&self) -> LanguageIdentifier {
+        match self.resolve() {
+            Language::German => "de-DE",
+            _ => "en-US",
+        }
+        .parse()
+        .unwrap_or_default()
+    }
+
+    pub fn resolve(&self) -> Language {
+        if *self != Language::System {
+            return *self;
+        }
+
+        let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .unwrap_or_default();
+
+        if locale.to_lowercase().starts_with("de") {
+            Language::German
+        } else {
+            Language::English
+        }
+    }
+}
+
+pub fn set_language(language: Language) {
+    if let Ok(mut current) = CURRENT.write() {
+        *current = language.resolve();
+    }
+}
+
+pub fn current() -> Language {
+    CURRENT
+        .read()
+        .map(|language| *language)
+        .unwrap_or(Language::English)
+}
+
+pub fn translate(id: &str, args: Option<&FluentArgs>) -> String {
+    let language = current();
+
+    let catalog = |language| {
+        CATALOGS
+            .iter()
+            .find(|catalog: &&Catalog| catalog.language == language)
+    };
+
+    catalog(language)
+        .and_then(|catalog| catalog.format(id, args))
+        .or_else(|| catalog(Language::English).and_then(|catalog| catalog.format(id, args)))
+        .unwrap_or_else(|| id.to_string())
+}
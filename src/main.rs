@@ -1,30 +1,30 @@
 mod communication_layer;
 mod config;
 mod data_types;
+mod i18n;
+mod search;
 mod ui;
 
 use eframe::{Error, NativeOptions, run_native};
 use egui::ViewportBuilder;
 
-use ui::MainUi;
+use ui::{DEFAULT_WINDOW_SIZE, MainUi};
 
 fn main() -> Result<(), Error> {
-    let mut viewport = ViewportBuilder::default()
+    let viewport = ViewportBuilder::default()
         .with_min_inner_size([500.0, 400.0])
-        .with_max_inner_size([500.0, 400.0])
-        .with_inner_size([500.0, 400.0]);
-
-    viewport.resizable = Some(false);
-    viewport.maximize_button = Some(false);
+        .with_inner_size(DEFAULT_WINDOW_SIZE)
+        .with_resizable(true);
 
     let options = NativeOptions {
         viewport,
+        persist_window: true,
         ..Default::default()
     };
 
     run_native(
         "Simple REST Client",
         options,
-        Box::new(|_| Ok(Box::<MainUi>::new(MainUi::new()))),
+        Box::new(|cc| Ok(Box::<MainUi>::new(MainUi::new(&cc.egui_ctx, cc.storage)))),
     )
 }
@@ -0,0 +1,619 @@
+use std::{cmp::Ordering, ops::Range};
+
+use anyhow::{Result, anyhow};
+
+use crate::{
+    data_types::{Movie, SortColumn},
+    t,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Field {
+    Title,
+    Description,
+    Any,
+}
+
+enum Condition {
+    Text {
+        field: Field,
+        text: String,
+        fuzzy: bool,
+    },
+    Empty(Field),
+    Year(Option<i32>, Option<i32>),
+    Id(i32),
+}
+
+struct Term {
+    negated: bool,
+    condition: Condition,
+}
+
+pub struct Query {
+    terms: Vec<Term>,
+}
+
+pub struct SearchHit {
+    pub index: usize,
+    pub score: i32,
+}
+
+struct Entry {
+    id: i32,
+    title: String,
+    description: String,
+    release_year: i32,
+}
+
+pub struct MovieIndex {
+    entries: Vec<Entry>,
+}
+
+fn fold(text: &str) -> String {
+    text.chars().map(fold_char).collect()
+}
+
+fn fold_char(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+fn tokenize(input: &str) -> Result<Vec<(String, Option<usize>)>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+        if chars.peek().is_none() {
+            return Ok(tokens);
+        }
+
+        let mut token = String::new();
+        let mut quote_start = None;
+
+        while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+            if c != '"' {
+                token.push(c);
+                continue;
+            }
+
+            quote_start.get_or_insert(token.len());
+
+            loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some(c) => token.push(c),
+                    None => return Err(anyhow!(t!("search-missing-quote"))),
+                }
+            }
+        }
+
+        tokens.push((token, quote_start));
+    }
+}
+
+fn parse_year(value: &str) -> Result<i32> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| anyhow!(t!("search-invalid-year", value = value)))
+}
+
+fn parse_year_range(value: &str) -> Result<Condition> {
+    let (from, to) = if let Some((from, to)) = value.split_once("..") {
+        (
+            (!from.is_empty()).then(|| parse_year(from)).transpose()?,
+            (!to.is_empty()).then(|| parse_year(to)).transpose()?,
+        )
+    } else if let Some(year) = value.strip_prefix(">=") {
+        (Some(parse_year(year)?), None)
+    } else if let Some(year) = value.strip_prefix("<=") {
+        (None, Some(parse_year(year)?))
+    } else if let Some(year) = value.strip_prefix('>') {
+        let year = parse_year(year)?
+            .checked_add(1)
+            .ok_or_else(|| anyhow!(t!("search-invalid-year", value = year)))?;
+
+        (Some(year), None)
+    } else if let Some(year) = value.strip_prefix('<') {
+        let year = parse_year(year)?
+            .checked_sub(1)
+            .ok_or_else(|| anyhow!(t!("search-invalid-year", value = year)))?;
+
+        (None, Some(year))
+    } else {
+        let year = parse_year(value)?;
+
+        (Some(year), Some(year))
+    };
+
+    Ok(Condition::Year(from, to))
+}
+
+fn parse_term(token: &str, quote_start: Option<usize>) -> Result<Term> {
+    let (negated, token, quote_start) = match token.strip_prefix('-') {
+        Some(rest) if !rest.is_empty() && quote_start != Some(0) => {
+            (true, rest, quote_start.map(|start| start - 1))
+        }
+        _ => (false, token, quote_start),
+    };
+
+    let quoted_from = quote_start.unwrap_or(usize::MAX);
+
+    let field = token
+        .find(':')
+        .filter(|&position| position < quoted_from)
+        .map(|position| (&token[..position], &token[position + 1..]));
+
+    let condition = match field {
+        None => Condition::Text {
+            field: Field::Any,
+            text: fold(token),
+            fuzzy: true,
+        },
+        Some((name, value)) => {
+            let quoted = quoted_from != usize::MAX;
+
+            let text_field = match name.to_lowercase().as_str() {
+                "title" | "t" => Field::Title,
+                "desc" | "description" | "d" => Field::Description,
+                "year" | "y" => {
+                    return Ok(Term {
+                        negated,
+                        condition: parse_year_range(value)?,
+                    });
+                }
+                "id" => {
+                    let id = value
+                        .parse()
+                        .map_err(|_| anyhow!(t!("search-invalid-id", value = value)))?;
+
+                    return Ok(Term {
+                        negated,
+                        condition: Condition::Id(id),
+                    });
+                }
+                _ => return Err(anyhow!(t!("search-unknown-field", name = name))),
+            };
+
+            if !quoted && value == "empty" {
+                Condition::Empty(text_field)
+            } else {
+                let (fuzzy, text) = match value.strip_prefix('~') {
+                    Some(text) => (true, text),
+                    None => (false, value),
+                };
+
+                if text.is_empty() {
+                    return Err(anyhow!(t!("search-missing-value", name = name)));
+                }
+
+                Condition::Text {
+                    field: text_field,
+                    text: fold(text),
+                    fuzzy,
+                }
+            }
+        }
+    };
+
+    Ok(Term { negated, condition })
+}
+
+fn fuzzy_match(pattern: &str, text: &str) -> Option<(i32, Vec<usize>)> {
+    let pattern: Vec<char> = pattern.chars().collect();
+
+    if pattern.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    let mut positions = Vec::with_capacity(pattern.len());
+    let mut score = 0;
+    let mut previous: Option<(usize, char)> = None;
+    let mut next = 0;
+
+    for (position, c) in text.chars().enumerate() {
+        if next == pattern.len() {
+            break;
+        }
+
+        if fold_char(c) == pattern[next] {
+            score += 1;
+
+            match previous {
+                Some((last, _)) if last + 1 == position && positions.last() == Some(&last) => {
+                    score += 5;
+                }
+                Some((_, before)) if !before.is_alphanumeric() => score += 3,
+                None => score += 3,
+                _ => {}
+            }
+
+            if let Some(&last) = positions.last() {
+                score -= ((position - last - 1) as i32).min(3);
+            }
+
+            positions.push(position);
+            next += 1;
+        }
+
+        previous = Some((position, c));
+    }
+
+    (next == pattern.len()).then_some((score, positions))
+}
+
+pub fn fuzzy_score(pattern: &str, text: &str) -> Option<i32> {
+    fuzzy_match(&fold(pattern), text).map(|(score, _)| score)
+}
+
+fn substring_ranges(pattern: &str, text: &str) -> Vec<Range<usize>> {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().map(fold_char).collect();
+    let mut ranges = Vec::new();
+
+    if pattern.is_empty() || pattern.len() > text.len() {
+        return ranges;
+    }
+
+    let mut start = 0;
+
+    while start + pattern.len() <= text.len() {
+        if text[start..start + pattern.len()] == pattern[..] {
+            ranges.push(start..start + pattern.len());
+            start += pattern.len();
+        } else {
+            start += 1;
+        }
+    }
+
+    ranges
+}
+
+impl Field {
+    fn covers(&self, field: Field) -> bool {
+        *self == Field::Any || *self == field
+    }
+}
+
+impl Query {
+    pub fn parse(input: &str) -> Result<Self> {
+        let terms = tokenize(input)?
+            .into_iter()
+            .map(|(token, quote_start)| parse_term(&token, quote_start))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { terms })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn ranked(&self) -> bool {
+        self.terms.iter().any(|term| {
+            !term.negated && matches!(term.condition, Condition::Text { fuzzy: true, .. })
+        })
+    }
+
+    fn score(&self, entry: &Entry) -> Option<i32> {
+        let mut total = 0;
+
+        for term in self.terms.iter() {
+            let score = match &term.condition {
+                Condition::Text {
+                    field: Field::Any,
+                    text,
+                    ..
+                } => fuzzy_match(text, &entry.title)
+                    .map(|(score, _)| score)
+                    .or_else(|| {
+                        (entry.description.contains(text.as_str())
+                            || entry.release_year.to_string().contains(text.as_str()))
+                        .then_some(0)
+                    }),
+                Condition::Text {
+                    field: Field::Title,
+                    text,
+                    fuzzy: true,
+                } => fuzzy_match(text, &entry.title).map(|(score, _)| score),
+                Condition::Text {
+                    field: Field::Description,
+                    text,
+                    fuzzy: true,
+                } => fuzzy_match(text, &entry.description).map(|(score, _)| score),
+                Condition::Text { field, text, .. } => {
+                    let haystack = if *field == Field::Title {
+                        &entry.title
+                    } else {
+                        &entry.description
+                    };
+
+                    haystack.contains(text.as_str()).then_some(0)
+                }
+                Condition::Empty(field) => {
+                    let value = if *field == Field::Title {
+                        &entry.title
+                    } else {
+                        &entry.description
+                    };
+
+                    value.trim().is_empty().then_some(0)
+                }
+                Condition::Year(from, to) => (from.is_none_or(|from| entry.release_year >= from)
+                    && to.is_none_or(|to| entry.release_year <= to))
+                .then_some(0),
+                Condition::Id(id) => (entry.id == *id).then_some(0),
+            };
+
+            match (score, term.negated) {
+                (Some(score), false) => total += score,
+                (None, true) => {}
+                _ => return None,
+            }
+        }
+
+        Some(total)
+    }
+
+    pub fn highlights(&self, field: Field, text: &str) -> Vec<Range<usize>> {
+        let mut ranges = Vec::new();
+
+        for term in self.terms.iter().filter(|term| !term.negated) {
+            let Condition::Text {
+                field: term_field,
+                text: pattern,
+                fuzzy,
+            } = &term.condition
+            else {
+                continue;
+            };
+
+            if !term_field.covers(field) {
+                continue;
+            }
+
+            let substrings = substring_ranges(pattern, text);
+
+            if !*fuzzy || !substrings.is_empty() {
+                ranges.extend(substrings);
+            } else if (*term_field != Field::Any || field == Field::Title)
+                && let Some((_, positions)) = fuzzy_match(pattern, text)
+            {
+                ranges.extend(positions.into_iter().map(|position| position..position + 1));
+            }
+        }
+
+        ranges.sort_by_key(|range| range.start);
+
+        ranges
+    }
+}
+
+impl MovieIndex {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn rebuild(&mut self, movies: &[Movie]) {
+        self.entries = movies
+            .iter()
+            .map(|movie| Entry {
+                id: movie.id,
+                title: fold(&movie.title),
+                description: fold(&movie.description),
+                release_year: movie.release_year,
+            })
+            .collect();
+    }
+
+    pub fn compare(&self, a: usize, b: usize, column: SortColumn) -> Ordering {
+        let (a, b) = (&self.entries[a], &self.entries[b]);
+
+        match column {
+            SortColumn::Title => a.title.cmp(&b.title),
+            SortColumn::Description => a.description.cmp(&b.description),
+            SortColumn::ReleaseYear => a.release_year.cmp(&b.release_year),
+        }
+        .then(a.id.cmp(&b.id))
+    }
+
+    pub fn search(&self, query: &Query) -> Vec<SearchHit> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| query.score(entry).map(|score| SearchHit { index, score }))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn movie(id: i32, title: &str, description: &str, release_year: i32) -> Movie {
+        Movie {
+            id,
+            title: title.to_string(),
+            description: description.to_string(),
+            release_year,
+            ..Default::default()
+        }
+    }
+
+    fn search(query: &str, movies: &[Movie]) -> Vec<i32> {
+        let mut index = MovieIndex::new();
+        index.rebuild(movies);
+
+        let mut hits = index.search(&Query::parse(query).unwrap());
+        hits.sort_by(|a, b| b.score.cmp(&a.score).then(a.index.cmp(&b.index)));
+
+        hits.into_iter().map(|hit| movies[hit.index].id).collect()
+    }
+
+    #[test]
+    fn parses_request_example() {
+        let query = Query::parse(r#"year:1990..1999 title:~"star" -desc:empty"#).unwrap();
+
+        assert_eq!(query.terms.len(), 3);
+        assert!(matches!(
+            query.terms[0],
+            Term {
+                negated: false,
+                condition: Condition::Year(Some(1990), Some(1999)),
+            }
+        ));
+        assert!(matches!(
+            &query.terms[1],
+            Term {
+                negated: false,
+                condition: Condition::Text {
+                    field: Field::Title,
+                    text,
+                    fuzzy: true,
+                },
+            } if text == "star"
+        ));
+        assert!(matches!(
+            query.terms[2],
+            Term {
+                negated: true,
+                condition: Condition::Empty(Field::Description),
+            }
+        ));
+    }
+
+    #[test]
+    fn parses_quoted_values() {
+        let query = Query::parse(r#"title:"Star Wars" desc:"empty" "a b""#).unwrap();
+
+        assert!(matches!(
+            &query.terms[0].condition,
+            Condition::Text {
+                field: Field::Title,
+                text,
+                fuzzy: false,
+            } if text == "star wars"
+        ));
+        assert!(matches!(
+            &query.terms[1].condition,
+            Condition::Text {
+                field: Field::Description,
+                text,
+                ..
+            } if text == "empty"
+        ));
+        assert!(matches!(
+            &query.terms[2].condition,
+            Condition::Text {
+                field: Field::Any,
+                text,
+                ..
+            } if text == "a b"
+        ));
+    }
+
+    #[test]
+    fn parses_negation() {
+        let query = Query::parse(r#"-id:7 -"-x" -"#).unwrap();
+
+        assert!(matches!(
+            query.terms[0],
+            Term {
+                negated: true,
+                condition: Condition::Id(7),
+            }
+        ));
+        assert!(query.terms[1].negated);
+        assert!(matches!(
+            &query.terms[1].condition,
+            Condition::Text { text, .. } if text == "-x"
+        ));
+        assert!(!query.terms[2].negated);
+    }
+
+    #[test]
+    fn parses_year_bounds() {
+        let query = Query::parse("year:>1990 y:<=2000 year:..1980").unwrap();
+
+        assert!(matches!(
+            query.terms[0].condition,
+            Condition::Year(Some(1991), None)
+        ));
+        assert!(matches!(
+            query.terms[1].condition,
+            Condition::Year(None, Some(2000))
+        ));
+        assert!(matches!(
+            query.terms[2].condition,
+            Condition::Year(None, Some(1980))
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_queries() {
+        assert!(Query::parse("genre:drama").is_err());
+        assert!(Query::parse(r#"title:"star"#).is_err());
+        assert!(Query::parse("title:").is_err());
+        assert!(Query::parse("year:abc").is_err());
+        assert!(Query::parse("id:x").is_err());
+        assert!(Query::parse("year:>2147483647").is_err());
+        assert!(Query::parse("year:<-2147483648").is_err());
+    }
+
+    #[test]
+    fn fuzzy_match_finds_positions_in_order() {
+        assert_eq!(fuzzy_match("sw", "star wars").unwrap().1, vec![0, 5]);
+        assert_eq!(fuzzy_match("", "star wars"), Some((0, Vec::new())));
+        assert!(fuzzy_match("ws", "star wars").is_some());
+        assert!(fuzzy_match("wz", "star wars").is_none());
+    }
+
+    #[test]
+    fn fuzzy_score_prefers_consecutive_and_word_start_matches() {
+        let consecutive = fuzzy_score("star", "star wars").unwrap();
+        let scattered = fuzzy_score("star", "sometimes tea arrives").unwrap();
+        let word_start = fuzzy_score("wars", "star wars").unwrap();
+        let inside_word = fuzzy_score("wars", "swarsh").unwrap();
+
+        assert!(consecutive > scattered);
+        assert!(word_start > inside_word);
+        assert_eq!(fuzzy_score("STAR", "star"), fuzzy_score("star", "star"));
+    }
+
+    #[test]
+    fn search_ranks_by_score() {
+        let movies = [
+            movie(1, "Sometimes Tea Arrives", "", 2001),
+            movie(2, "Star Wars", "", 1977),
+            movie(3, "Gone", "", 1999),
+        ];
+
+        assert_eq!(search("star", &movies), vec![2, 1]);
+        assert_eq!(search("-title:~star", &movies), vec![3]);
+        assert_eq!(search("year:1977..1999", &movies), vec![2, 3]);
+    }
+
+    #[test]
+    fn highlights_matching_ranges() {
+        let query = Query::parse("star").unwrap();
+        assert_eq!(query.highlights(Field::Title, "Star Wars"), vec![0..4]);
+
+        let query = Query::parse("sw").unwrap();
+        assert_eq!(
+            query.highlights(Field::Title, "Star Wars"),
+            vec![0..1, 5..6]
+        );
+        assert!(query.highlights(Field::Description, "Star Wars").is_empty());
+
+        let query = Query::parse("desc:war -title:star").unwrap();
+        assert_eq!(
+            query.highlights(Field::Description, "War and war"),
+            vec![0..3, 8..11]
+        );
+        assert!(query.highlights(Field::Title, "Star Wars").is_empty());
+    }
+}
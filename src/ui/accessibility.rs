@@ -0,0 +1,54 @@
+use egui::{Response, accesskit};
+
+pub fn initial_focus(response: &Response) {
+    let id = response.id.with("initial_focus");
+
+    if response
+        .ctx
+        .data(|data| data.get_temp::<bool>(id).is_none())
+    {
+        response.ctx.data_mut(|data| data.insert_temp(id, true));
+        response.request_focus();
+    }
+}
+
+pub fn set_label(response: &Response, label: impl Into<String>) {
+    let label = label.into();
+
+    response
+        .ctx
+        .accesskit_node_builder(response.id, |node| node.set_label(label));
+}
+
+pub fn set_description(response: &Response, description: impl Into<String>) {
+    let description = description.into();
+
+    response
+        .ctx
+        .accesskit_node_builder(response.id, |node| node.set_description(description));
+}
+
+pub fn announce(response: &Response, urgent: bool) {
+    response.ctx.accesskit_node_builder(response.id, |node| {
+        node.set_live(if urgent {
+            accesskit::Live::Assertive
+        } else {
+            accesskit::Live::Polite
+        });
+    });
+}
+
+pub fn describe_row(response: &Response, cells: &[&str], selected: bool) {
+    let label = cells
+        .iter()
+        .filter(|cell| !cell.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    response.ctx.accesskit_node_builder(response.id, |node| {
+        node.set_role(accesskit::Role::Row);
+        node.set_label(label);
+        node.set_selected(selected);
+    });
+}
@@ -0,0 +1,264 @@
+use crate::{
+    data_types::{FieldErrors, PasswordPolicy},
+    t,
+    ui::{
+        accessibility::initial_focus,
+        dialog::{Dialog, DialogOutcome},
+        password::{PasswordInput, strength_meter},
+        validation::{field_error, mark_field},
+    },
+};
+
+use egui::{Align, Checkbox, Layout, Ui};
+
+pub struct ChangePasswordDialog {
+    username: String,
+    old_password: String,
+    new_password: String,
+    confirm_password: String,
+    policy: PasswordPolicy,
+    inputs: [PasswordInput; 3],
+    errors: FieldErrors,
+    submitted: bool,
+}
+
+impl ChangePasswordDialog {
+    pub fn new(username: String, policy: PasswordPolicy) -> Self {
+        Self {
+            username,
+            old_password: String::new(),
+            new_password: String::new(),
+            confirm_password: String::new(),
+            policy,
+            inputs: [PasswordInput::new(); 3],
+            errors: FieldErrors::new(),
+            submitted: false,
+        }
+    }
+
+    fn validate(&self) -> FieldErrors {
+        let mut errors = FieldErrors::new();
+
+        errors.require(
+            "old_password",
+            !self.old_password.is_empty(),
+            t!("validation-current-password-required"),
+        );
+        errors.require(
+            "new_password",
+            !self.new_password.is_empty(),
+            t!("validation-new-password-required"),
+        );
+
+        if let Some(violation) = self
+            .policy
+            .violations(&self.new_password, &self.username)
+            .into_iter()
+            .next()
+        {
+            errors.add("new_password", violation);
+        }
+
+        errors.require(
+            "confirm_password",
+            self.new_password == self.confirm_password,
+            t!("validation-passwords-mismatch"),
+        );
+
+        errors
+    }
+}
+
+impl Dialog for ChangePasswordDialog {
+    type Output = (String, String);
+
+    fn title(&self) -> String {
+        t!("change-password-title")
+    }
+
+    fn show(&mut self, ui: &mut Ui) -> Option<DialogOutcome<Self::Output>> {
+        let mut outcome = None;
+
+        ui.label(t!("change-password-intro"));
+
+        let mut edited = false;
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("label-current-password"));
+            let response = self.inputs[0]
+                .show(ui, &mut self.old_password)
+                .labelled_by(label.id);
+            mark_field(ui, &response, &self.errors, "old_password");
+            initial_focus(&response);
+            edited |= response.changed();
+        });
+        field_error(ui, &self.errors, "old_password");
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("label-new-password"));
+            let response = self.inputs[1]
+                .show(ui, &mut self.new_password)
+                .labelled_by(label.id);
+            mark_field(ui, &response, &self.errors, "new_password");
+            edited |= response.changed();
+        });
+
+        strength_meter(ui, &self.policy, &self.new_password, &self.username);
+        field_error(ui, &self.errors, "new_password");
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("label-confirm-password"));
+            let response = self.inputs[2]
+                .show(ui, &mut self.confirm_password)
+                .labelled_by(label.id);
+            mark_field(ui, &response, &self.errors, "confirm_password");
+            edited |= response.changed();
+        });
+        field_error(ui, &self.errors, "confirm_password");
+
+        if edited && self.submitted {
+            self.errors = self.validate();
+        }
+
+        ui.horizontal(|ui| {
+            ui.with_layout(Layout::right_to_left(Align::RIGHT), |ui| {
+                if ui.button(t!("change-password-button")).clicked() {
+                    outcome = self.submit().map(DialogOutcome::Submitted);
+                }
+
+                if ui.button(t!("button-cancel")).clicked() {
+                    outcome = Some(DialogOutcome::Cancelled);
+                }
+            });
+        });
+
+        outcome
+    }
+
+    fn submit(&mut self) -> Option<Self::Output> {
+        self.submitted = true;
+        self.errors = self.validate();
+
+        self.errors
+            .is_empty()
+            .then(|| (self.old_password.clone(), self.new_password.clone()))
+    }
+
+    fn apply_field_errors(&mut self, errors: &FieldErrors) -> bool {
+        if !errors.any_of(&["old_password", "new_password"]) {
+            return false;
+        }
+
+        self.errors = errors.clone();
+
+        true
+    }
+}
+
+pub struct DeleteAccountDialog {
+    password: String,
+    password_input: PasswordInput,
+    understood: bool,
+    errors: FieldErrors,
+    submitted: bool,
+}
+
+impl DeleteAccountDialog {
+    pub fn new() -> Self {
+        Self {
+            password: String::new(),
+            password_input: PasswordInput::new(),
+            understood: false,
+            errors: FieldErrors::new(),
+            submitted: false,
+        }
+    }
+
+    fn validate(&self) -> FieldErrors {
+        let mut errors = FieldErrors::new();
+
+        errors.require(
+            "password",
+            !self.password.is_empty(),
+            t!("validation-password-required"),
+        );
+        errors.require(
+            "understood",
+            self.understood,
+            t!("validation-confirm-delete-account"),
+        );
+
+        errors
+    }
+}
+
+impl Dialog for DeleteAccountDialog {
+    type Output = String;
+
+    fn title(&self) -> String {
+        t!("delete-account-title")
+    }
+
+    fn show(&mut self, ui: &mut Ui) -> Option<DialogOutcome<String>> {
+        let mut outcome = None;
+
+        ui.label(t!("delete-account-intro"));
+
+        let mut edited = false;
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("label-password"));
+            let response = self
+                .password_input
+                .show(ui, &mut self.password)
+                .labelled_by(label.id);
+            mark_field(ui, &response, &self.errors, "password");
+            initial_focus(&response);
+            edited |= response.changed();
+        });
+        field_error(ui, &self.errors, "password");
+
+        let response = ui.add(Checkbox::new(
+            &mut self.understood,
+            t!("delete-account-understood"),
+        ));
+        mark_field(ui, &response, &self.errors, "understood");
+        edited |= response.changed();
+        field_error(ui, &self.errors, "understood");
+
+        if edited && self.submitted {
+            self.errors = self.validate();
+        }
+
+        ui.horizontal(|ui| {
+            ui.with_layout(Layout::right_to_left(Align::RIGHT), |ui| {
+                if ui.button(t!("delete-account-button")).clicked() {
+                    outcome = self.submit().map(DialogOutcome::Submitted);
+                }
+
+                if ui.button(t!("button-cancel")).clicked() {
+                    outcome = Some(DialogOutcome::Cancelled);
+                }
+            });
+        });
+
+        outcome
+    }
+
+    fn submit(&mut self) -> Option<String> {
+        self.submitted = true;
+        self.errors = self.validate();
+
+        self.errors.is_empty().then(|| self.password.clone())
+    }
+
+    fn apply_field_errors(&mut self, errors: &FieldErrors) -> bool {
+        if !errors.any_of(&["password"]) {
+            return false;
+        }
+
+        self.errors = errors.clone();
+
+        true
+    }
+}
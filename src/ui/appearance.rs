@@ -0,0 +1,150 @@
+use crate::{
+    data_types::{AccentColor, AppearanceSettings, ThemeMode},
+    t,
+    ui::{
+        accessibility::initial_focus,
+        dialog::{Dialog, DialogOutcome},
+    },
+};
+
+use egui::{
+    Align, Color32, ComboBox, Context, Layout, RichText, Slider, Theme, ThemePreference, Ui,
+    Visuals,
+};
+
+pub const MIN_UI_SCALE: f32 = 0.5;
+pub const MAX_UI_SCALE: f32 = 3.0;
+
+fn accent_visuals(mut visuals: Visuals, accent: AccentColor) -> Visuals {
+    let Some([r, g, b]) = accent.rgb() else {
+        return visuals;
+    };
+
+    let accent = Color32::from_rgb(r, g, b);
+
+    visuals.hyperlink_color = accent;
+    visuals.selection.bg_fill = if visuals.dark_mode {
+        accent.lerp_to_gamma(Color32::BLACK, 0.35)
+    } else {
+        accent.lerp_to_gamma(Color32::WHITE, 0.55)
+    };
+    visuals.widgets.hovered.bg_stroke.color = accent;
+    visuals.widgets.active.bg_stroke.color = accent;
+
+    visuals
+}
+
+pub fn apply_appearance(ctx: &Context, settings: &AppearanceSettings) {
+    ctx.set_visuals_of(
+        Theme::Dark,
+        accent_visuals(Visuals::dark(), settings.accent),
+    );
+    ctx.set_visuals_of(
+        Theme::Light,
+        accent_visuals(Visuals::light(), settings.accent),
+    );
+
+    ctx.set_theme(match settings.theme {
+        ThemeMode::Light => ThemePreference::Light,
+        ThemeMode::Dark => ThemePreference::Dark,
+        ThemeMode::System => ThemePreference::System,
+    });
+
+    ctx.set_zoom_factor(settings.ui_scale.clamp(MIN_UI_SCALE, MAX_UI_SCALE));
+}
+
+pub struct AppearanceDialog {
+    settings: AppearanceSettings,
+}
+
+impl AppearanceDialog {
+    pub fn new(settings: AppearanceSettings) -> Self {
+        Self { settings }
+    }
+}
+
+impl Dialog for AppearanceDialog {
+    type Output = AppearanceSettings;
+
+    fn title(&self) -> String {
+        t!("appearance-title")
+    }
+
+    fn show(&mut self, ui: &mut Ui) -> Option<DialogOutcome<AppearanceSettings>> {
+        let mut outcome = None;
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("appearance-theme"));
+
+            for theme in [ThemeMode::Light, ThemeMode::Dark, ThemeMode::System] {
+                let response = ui
+                    .selectable_value(&mut self.settings.theme, theme, theme.name())
+                    .labelled_by(label.id);
+
+                if theme == ThemeMode::Light {
+                    initial_focus(&response);
+                }
+            }
+        });
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("appearance-accent"));
+
+            ComboBox::from_id_salt("accent_color")
+                .selected_text(self.settings.accent.name())
+                .show_ui(ui, |ui| {
+                    for accent in AccentColor::ALL {
+                        let text = match accent.rgb() {
+                            Some([r, g, b]) => RichText::new(format!("⏺ {}", accent.name()))
+                                .color(Color32::from_rgb(r, g, b)),
+                            None => RichText::new(accent.name()),
+                        };
+
+                        ui.selectable_value(&mut self.settings.accent, accent, text);
+                    }
+                })
+                .response
+                .labelled_by(label.id);
+        });
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("appearance-ui-scale"));
+            ui.add(
+                Slider::new(&mut self.settings.ui_scale, MIN_UI_SCALE..=MAX_UI_SCALE)
+                    .step_by(0.05)
+                    .custom_formatter(|value, _| format!("{:.0}%", value * 100.0))
+                    .custom_parser(|text| {
+                        text.trim_end_matches('%')
+                            .trim()
+                            .parse::<f64>()
+                            .ok()
+                            .map(|percent| percent / 100.0)
+                    }),
+            )
+            .on_hover_text(t!("appearance-ui-scale-hint"))
+            .labelled_by(label.id);
+
+            if ui.button(t!("button-reset")).clicked() {
+                self.settings.ui_scale = 1.0;
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.with_layout(Layout::right_to_left(Align::RIGHT), |ui| {
+                if ui.button(t!("button-save")).clicked() {
+                    outcome = self.submit().map(DialogOutcome::Submitted);
+                }
+
+                if ui.button(t!("button-cancel")).clicked() {
+                    outcome = Some(DialogOutcome::Cancelled);
+                }
+            });
+        });
+
+        outcome
+    }
+
+    fn submit(&mut self) -> Option<AppearanceSettings> {
+        Some(self.settings.clone())
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{
+    data_types::Command,
+    search::fuzzy_score,
+    t,
+    ui::accessibility::{set_description, set_label},
+};
+
+use egui::{
+    Align, Align2, Context, Key, Layout, Modifiers, RichText, ScrollArea, TextEdit, Window,
+};
+
+pub enum PaletteOutcome {
+    Run(Command),
+    Closed,
+}
+
+pub struct CommandPalette {
+    query: String,
+    selected: usize,
+}
+
+impl CommandPalette {
+    pub fn new() -> Self {
+        Self {
+            query: String::new(),
+            selected: 0,
+        }
+    }
+
+    fn matches(&self, commands: &[(Command, String)]) -> Vec<(Command, String)> {
+        let mut matches: Vec<(i32, usize)> = commands
+            .iter()
+            .enumerate()
+            .filter_map(|(index, (command, _))| {
+                fuzzy_score(self.query.trim(), &command.name()).map(|score| (score, index))
+            })
+            .collect();
+
+        matches.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+        matches
+            .into_iter()
+            .map(|(_, index)| commands[index].clone())
+            .collect()
+    }
+
+    pub fn show(
+        &mut self,
+        ctx: &Context,
+        commands: &[(Command, String)],
+    ) -> Option<PaletteOutcome> {
+        let matches = self.matches(commands);
+        let mut outcome = None;
+
+        ctx.input_mut(|input| {
+            if input.consume_key(Modifiers::NONE, Key::Escape) {
+                outcome = Some(PaletteOutcome::Closed);
+            }
+
+            if input.consume_key(Modifiers::NONE, Key::ArrowDown) {
+                self.selected += 1;
+            }
+
+            if input.consume_key(Modifiers::NONE, Key::ArrowUp) {
+                self.selected = self.selected.saturating_sub(1);
+            }
+
+            if input.consume_key(Modifiers::NONE, Key::Enter)
+                && let Some((command, _)) = matches.get(self.selected)
+            {
+                outcome = Some(PaletteOutcome::Run(*command));
+            }
+        });
+
+        self.selected = self.selected.min(matches.len().saturating_sub(1));
+
+        let response = Window::new(t!("command-open-palette"))
+            .title_bar(false)
+            .resizable(false)
+            .collapsible(false)
+            .anchor(Align2::CENTER_TOP, [0.0, 60.0])
+            .fixed_size([400.0, 300.0])
+            .show(ctx, |ui| {
+                let response = ui.add(
+                    TextEdit::singleline(&mut self.query)
+                        .hint_text(t!("palette-hint"))
+                        .desired_width(f32::INFINITY),
+                );
+                set_label(&response, t!("command-open-palette"));
+                response.request_focus();
+
+                if let Some((command, _)) = matches.get(self.selected) {
+                    set_description(&response, command.name());
+                }
+
+                if response.changed() {
+                    self.selected = 0;
+                }
+
+                ui.separator();
+
+                ScrollArea::vertical().auto_shrink(false).show(ui, |ui| {
+                    if matches.is_empty() {
+                        ui.label(t!("palette-no-matches"));
+                    }
+
+                    for (index, (command, shortcut)) in matches.iter().enumerate() {
+                        let selected = index == self.selected;
+
+                        let response = ui
+                            .horizontal(|ui| {
+                                let response = ui.selectable_label(selected, command.name());
+
+                                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                                    ui.label(RichText::new(shortcut).weak());
+                                });
+
+                                response
+                            })
+                            .inner;
+
+                        if !shortcut.is_empty() {
+                            set_label(
+                                &response,
+                                t!(
+                                    "a11y-command-shortcut",
+                                    command = command.name(),
+                                    shortcut = shortcut.as_str()
+                                ),
+                            );
+                        }
+
+                        if selected {
+                            response.scroll_to_me(None);
+                        }
+
+                        if response.clicked() {
+                            outcome = Some(PaletteOutcome::Run(*command));
+                        }
+                    }
+                });
+            });
+
+        if let Some(response) = response
+            && response.response.clicked_elsewhere()
+        {
+            outcome = outcome.or(Some(PaletteOutcome::Closed));
+        }
+
+        outcome
+    }
+}
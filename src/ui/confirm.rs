@@ -0,0 +1,63 @@
+use crate::{
+    t,
+    ui::{
+        accessibility::{initial_focus, set_description},
+        dialog::{Dialog, DialogOutcome},
+    },
+};
+
+use egui::{Align, Layout, Ui};
+
+pub struct ConfirmDialog {
+    title: String,
+    message: String,
+}
+
+impl ConfirmDialog {
+    pub fn new(title: String, message: String) -> Self {
+        Self { title, message }
+    }
+}
+
+impl Dialog for ConfirmDialog {
+    type Output = ();
+
+    fn title(&self) -> String {
+        self.title.clone()
+    }
+
+    fn show(&mut self, ui: &mut Ui) -> Option<DialogOutcome<()>> {
+        let mut outcome = None;
+
+        ui.label(self.message.as_str());
+
+        ui.horizontal(|ui| {
+            ui.with_layout(Layout::right_to_left(Align::RIGHT), |ui| {
+                let confirm = ui.button(t!("button-confirm"));
+                set_description(&confirm, self.message.as_str());
+
+                if confirm.clicked() {
+                    outcome = Some(DialogOutcome::Submitted(()));
+                }
+
+                let cancel = ui.button(t!("button-cancel"));
+                set_description(&cancel, self.message.as_str());
+                initial_focus(&cancel);
+
+                if cancel.clicked() {
+                    outcome = Some(DialogOutcome::Cancelled);
+                }
+            });
+        });
+
+        outcome
+    }
+
+    fn submit(&mut self) -> Option<()> {
+        Some(())
+    }
+
+    fn modal(&self) -> bool {
+        true
+    }
+}
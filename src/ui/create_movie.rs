@@ -1,106 +1,359 @@
-use crate::{data_types::Movie, ui::dialog::Dialog};
+use std::path::PathBuf;
 
-use egui::{Align, Context, DragValue, Layout, Window};
-use std::any::Any;
+use crate::{
+    data_types::{FieldErrors, Movie},
+    i18n, t,
+    ui::{
+        accessibility::initial_focus,
+        dialog::{Dialog, DialogOutcome},
+        movie_details::extras_grid,
+        validation::{field_error, mark_field},
+    },
+};
+
+use egui::{Align, CollapsingHeader, DragValue, Layout, Ui};
+use rfd::FileDialog;
 
 pub struct CreateMovieDialog {
     movie: Movie,
+    genres: String,
+    director: String,
+    rating: String,
+    original_rating: String,
+    runtime: String,
+    poster_url: String,
+    poster_file: String,
     update_mode: bool,
-    changed: bool,
+    errors: FieldErrors,
+    submitted: bool,
 }
 
 impl Clone for CreateMovieDialog {
     fn clone(&self) -> Self {
         Self {
             movie: self.movie.clone(),
+            genres: self.genres.clone(),
+            director: self.director.clone(),
+            rating: self.rating.clone(),
+            original_rating: self.original_rating.clone(),
+            runtime: self.runtime.clone(),
+            poster_url: self.poster_url.clone(),
+            poster_file: self.poster_file.clone(),
             update_mode: self.update_mode,
-            changed: self.changed,
+            errors: self.errors.clone(),
+            submitted: self.submitted,
         }
     }
 }
 
+fn optional_text(value: &str) -> Option<String> {
+    let value = value.trim();
+
+    (!value.is_empty()).then(|| value.to_string())
+}
+
 impl CreateMovieDialog {
     pub fn new(movie: Option<Movie>) -> Self {
-        match movie {
-            Some(m) => Self {
-                movie: m,
-                update_mode: true,
-                changed: false,
-            },
-            None => Self {
-                movie: Movie::default(),
-                update_mode: false,
-                changed: false,
-            },
+        let update_mode = movie.is_some();
+        let movie = movie.unwrap_or_default();
+
+        let rating = movie
+            .rating
+            .map(|rating| i18n::format_number(rating as f64, 1))
+            .unwrap_or_default();
+
+        Self {
+            genres: movie.genres.clone().unwrap_or_default().join(", "),
+            director: movie.director.clone().unwrap_or_default(),
+            original_rating: rating.clone(),
+            rating,
+            runtime: movie
+                .runtime
+                .map(|runtime| runtime.to_string())
+                .unwrap_or_default(),
+            poster_url: movie.poster_url.clone().unwrap_or_default(),
+            poster_file: String::new(),
+            movie,
+            update_mode,
+            errors: FieldErrors::new(),
+            submitted: false,
         }
     }
 
-    pub fn get_movie(&self) -> Option<Movie> {
-        if self.valid() {
-            Some(self.movie.clone())
-        } else {
-            None
+    fn rating(&self) -> Result<Option<f32>, ()> {
+        if self.rating == self.original_rating {
+            return Ok(self.movie.rating);
+        }
+
+        match optional_text(&self.rating) {
+            None => Ok(None),
+            Some(rating) => match i18n::parse_number(&rating) {
+                Some(rating) if (0.0..=10.0).contains(&rating) => Ok(Some(rating as f32)),
+                _ => Err(()),
+            },
         }
     }
 
-    fn valid(&self) -> bool {
-        !self.movie.title.is_empty()
-            && !self.movie.description.is_empty()
-            && self.movie.release_year > -1
+    fn runtime(&self) -> Result<Option<u32>, ()> {
+        optional_text(&self.runtime)
+            .map(|runtime| runtime.parse::<u32>().map_err(|_| ()))
+            .transpose()
+    }
+
+    fn poster_file(&self) -> Option<PathBuf> {
+        optional_text(&self.poster_file).map(PathBuf::from)
+    }
+
+    fn validate(&self) -> FieldErrors {
+        let mut errors = FieldErrors::new();
+
+        errors.require(
+            "title",
+            !self.movie.title.is_empty(),
+            t!("validation-title-required"),
+        );
+        errors.require(
+            "description",
+            !self.movie.description.is_empty(),
+            t!("validation-description-required"),
+        );
+        errors.require(
+            "release_year",
+            self.movie.release_year > -1,
+            t!("validation-release-year-required"),
+        );
+        errors.require(
+            "rating",
+            self.rating().is_ok(),
+            t!("validation-rating-range"),
+        );
+        errors.require(
+            "runtime",
+            self.runtime().is_ok(),
+            t!("validation-runtime-minutes"),
+        );
+        errors.require(
+            "poster_file",
+            self.poster_file().is_none_or(|path| path.is_file()),
+            t!("validation-poster-file-missing"),
+        );
+
+        errors
     }
 }
 
 impl Dialog for CreateMovieDialog {
-    fn show(&mut self, ctx: &Context, open: &mut bool) {
-        let title = if self.update_mode { "Update" } else { "Create" };
-
-        Window::new(title.to_owned() + " Movie")
-            .open(open)
-            .show(ctx, |ui| {
-                ui.label("Create a new movie");
-
-                ui.horizontal(|ui| {
-                    ui.label("Title:");
-                    ui.text_edit_singleline(&mut self.movie.title).highlight();
-                });
-
-                ui.horizontal(|ui| {
-                    ui.label("Description:");
-                    ui.text_edit_multiline(&mut self.movie.description)
-                        .highlight();
-                });
-
-                ui.horizontal(|ui| {
-                    ui.label("Release Year:");
-                    ui.add(
-                        DragValue::new(&mut self.movie.release_year)
-                            .range(1900..=2100)
-                            .speed(1.0)
-                            .suffix(" Year"),
-                    );
-                });
-
-                ui.horizontal(|ui| {
-                    ui.with_layout(Layout::right_to_left(Align::RIGHT), |ui| {
-                        if ui.button(title).clicked() {
-                            if self.valid() {
-                                self.changed = true;
-                            }
-                        }
-                    });
-                });
-            });
-    }
+    type Output = (Movie, Option<PathBuf>);
 
-    fn changed(&self) -> (bool, &str) {
+    fn title(&self) -> String {
         if self.update_mode {
-            (self.changed, "Update Movie")
+            t!("movie-update-title")
         } else {
-            (self.changed, "Create Movie")
+            t!("movie-create-title")
         }
     }
 
-    fn as_any(&self) -> &dyn Any {
-        self
+    fn show(&mut self, ui: &mut Ui) -> Option<DialogOutcome<(Movie, Option<PathBuf>)>> {
+        let mut outcome = None;
+
+        ui.label(t!("movie-create-intro"));
+
+        let mut edited = false;
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("movie-label-title"));
+            let response = ui
+                .text_edit_singleline(&mut self.movie.title)
+                .highlight()
+                .labelled_by(label.id);
+            mark_field(ui, &response, &self.errors, "title");
+            initial_focus(&response);
+            edited |= response.changed();
+        });
+        field_error(ui, &self.errors, "title");
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("movie-label-description"));
+            let response = ui
+                .text_edit_multiline(&mut self.movie.description)
+                .highlight()
+                .labelled_by(label.id);
+            mark_field(ui, &response, &self.errors, "description");
+            edited |= response.changed();
+        });
+        field_error(ui, &self.errors, "description");
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("movie-label-release-year"));
+            let response = ui
+                .add(
+                    DragValue::new(&mut self.movie.release_year)
+                        .range(1900..=2100)
+                        .speed(1.0)
+                        .suffix(t!("movie-year-suffix")),
+                )
+                .labelled_by(label.id);
+            mark_field(ui, &response, &self.errors, "release_year");
+            edited |= response.changed();
+        });
+        field_error(ui, &self.errors, "release_year");
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("movie-label-genres"));
+            ui.text_edit_singleline(&mut self.genres)
+                .on_hover_text(t!("movie-genres-hint"))
+                .labelled_by(label.id);
+        });
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("movie-label-director"));
+            ui.text_edit_singleline(&mut self.director)
+                .labelled_by(label.id);
+        });
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("movie-label-rating"));
+            let response = ui
+                .text_edit_singleline(&mut self.rating)
+                .on_hover_text(t!("movie-rating-hint"))
+                .labelled_by(label.id);
+            mark_field(ui, &response, &self.errors, "rating");
+            edited |= response.changed();
+        });
+        field_error(ui, &self.errors, "rating");
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("movie-label-runtime"));
+            let response = ui
+                .text_edit_singleline(&mut self.runtime)
+                .on_hover_text(t!("movie-runtime-hint"))
+                .labelled_by(label.id);
+            mark_field(ui, &response, &self.errors, "runtime");
+            edited |= response.changed();
+        });
+        field_error(ui, &self.errors, "runtime");
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("movie-label-poster-url"));
+            ui.text_edit_singleline(&mut self.poster_url)
+                .labelled_by(label.id);
+        });
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("movie-label-upload-poster"));
+            let response = ui
+                .text_edit_singleline(&mut self.poster_file)
+                .on_hover_text(t!("movie-upload-poster-hint"))
+                .labelled_by(label.id);
+            mark_field(ui, &response, &self.errors, "poster_file");
+            edited |= response.changed();
+
+            if ui.button(t!("movie-browse")).clicked()
+                && let Some(path) = FileDialog::new()
+                    .add_filter(
+                        t!("movie-image-filter"),
+                        &["png", "jpg", "jpeg", "gif", "webp"],
+                    )
+                    .pick_file()
+            {
+                self.poster_file = path.display().to_string();
+                edited = true;
+            }
+        });
+        field_error(ui, &self.errors, "poster_file");
+
+        let dropped = ui.ctx().input(|input| {
+            input
+                .raw
+                .dropped_files
+                .iter()
+                .find_map(|file| file.path.clone())
+        });
+
+        if let Some(path) = dropped {
+            self.poster_file = path.display().to_string();
+            edited = true;
+        }
+
+        if !self.movie.extras.is_empty() {
+            CollapsingHeader::new(t!("movie-other-fields", count = self.movie.extras.len())).show(
+                ui,
+                |ui| {
+                    extras_grid(ui, &self.movie.extras);
+                },
+            );
+        }
+
+        if edited && self.submitted {
+            self.errors = self.validate();
+        }
+
+        ui.horizontal(|ui| {
+            ui.with_layout(Layout::right_to_left(Align::RIGHT), |ui| {
+                let label = if self.update_mode {
+                    t!("movie-update-button")
+                } else {
+                    t!("movie-create-button")
+                };
+
+                if ui.button(label).clicked() {
+                    outcome = self.submit().map(DialogOutcome::Submitted);
+                }
+
+                if ui.button(t!("button-cancel")).clicked() {
+                    outcome = Some(DialogOutcome::Cancelled);
+                }
+            });
+        });
+
+        outcome
+    }
+
+    fn submit(&mut self) -> Option<(Movie, Option<PathBuf>)> {
+        self.submitted = true;
+        self.errors = self.validate();
+
+        if !self.errors.is_empty() {
+            return None;
+        }
+
+        let genres: Vec<String> = self.genres.split(',').filter_map(optional_text).collect();
+
+        let mut movie = Movie {
+            genres: (!genres.is_empty()).then_some(genres),
+            director: optional_text(&self.director),
+            rating: self.rating().ok().flatten(),
+            runtime: self.runtime().ok().flatten(),
+            poster_url: optional_text(&self.poster_url),
+            ..self.movie.clone()
+        };
+
+        for (key, set) in [
+            ("genres", movie.genres.is_some()),
+            ("director", movie.director.is_some()),
+            ("rating", movie.rating.is_some()),
+            ("runtime", movie.runtime.is_some()),
+            ("poster_url", movie.poster_url.is_some()),
+        ] {
+            if set {
+                movie.extras.remove(key);
+            }
+        }
+
+        Some((movie, self.poster_file()))
+    }
+
+    fn submit_on_enter(&self) -> bool {
+        false
+    }
+
+    fn apply_field_errors(&mut self, errors: &FieldErrors) -> bool {
+        if !errors.any_of(&["title", "description", "release_year", "rating", "runtime"]) {
+            return false;
+        }
+
+        self.errors = errors.clone();
+
+        true
     }
 }
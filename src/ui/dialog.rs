@@ -1,8 +1,254 @@
-use egui::Context;
-use std::any::Any;
+use crate::{
+    communication_layer::{ApiError, DataLayer},
+    data_types::FieldErrors,
+    ui::notifications::NotificationCenter,
+};
+
+use anyhow::Error;
+use egui::{Context, Id, Key, Modal, Modifiers, Ui, Window};
+
+pub enum DialogOutcome<T> {
+    Submitted(T),
+    Cancelled,
+}
 
 pub trait Dialog {
-    fn show(&mut self, ctx: &Context, open: &mut bool);
-    fn changed(&self) -> (bool, &str);
-    fn as_any(&self) -> &dyn Any;
+    type Output;
+
+    fn title(&self) -> String;
+
+    fn show(&mut self, ui: &mut Ui) -> Option<DialogOutcome<Self::Output>>;
+
+    fn submit(&mut self) -> Option<Self::Output>;
+
+    fn modal(&self) -> bool {
+        false
+    }
+
+    fn submit_on_enter(&self) -> bool {
+        true
+    }
+
+    fn apply_field_errors(&mut self, _errors: &FieldErrors) -> bool {
+        false
+    }
+}
+
+type Handler<T> = dyn FnMut(T, &mut DialogContext<'_>);
+
+pub struct DialogContext<'a> {
+    pub data_layer: &'a mut DataLayer,
+    pub notifications: &'a mut NotificationCenter,
+    current: Option<Id>,
+    next_id: u64,
+    opened: Vec<Box<dyn StackEntry>>,
+    failure: Option<(String, Error)>,
+}
+
+impl DialogContext<'_> {
+    pub fn open<D: Dialog + 'static>(
+        &mut self,
+        dialog: D,
+        handler: impl FnMut(D::Output, &mut DialogContext<'_>) + 'static,
+    ) {
+        self.next_id += 1;
+
+        self.opened.push(Box::new(Entry {
+            id: Id::new(("dialog", self.next_id)),
+            parent: self.current,
+            dialog,
+            handler: Box::new(handler),
+        }));
+    }
+
+    pub fn fail(&mut self, context: &str, error: Error) {
+        self.failure = Some((context.to_string(), error));
+    }
+}
+
+enum Status {
+    Open,
+    Closed,
+    Completed,
+}
+
+trait StackEntry {
+    fn id(&self) -> Id;
+
+    fn parent(&self) -> Option<Id>;
+
+    fn show(&mut self, ctx: &Context, focused: bool, dialogs: &mut DialogContext) -> Status;
+}
+
+struct Entry<D: Dialog> {
+    id: Id,
+    parent: Option<Id>,
+    dialog: D,
+    handler: Box<Handler<D::Output>>,
+}
+
+impl<D: Dialog> Entry<D> {
+    fn keyboard(&mut self, ctx: &Context) -> Option<DialogOutcome<D::Output>> {
+        if ctx.input_mut(|input| input.consume_key(Modifiers::NONE, Key::Escape)) {
+            return Some(DialogOutcome::Cancelled);
+        }
+
+        let submit_on_enter = self.dialog.submit_on_enter();
+
+        let submitted = ctx.input_mut(|input| {
+            input.consume_key(Modifiers::COMMAND, Key::Enter)
+                || (submit_on_enter && input.consume_key(Modifiers::NONE, Key::Enter))
+        });
+
+        if submitted {
+            self.dialog.submit().map(DialogOutcome::Submitted)
+        } else {
+            None
+        }
+    }
+}
+
+impl<D: Dialog> StackEntry for Entry<D> {
+    fn id(&self) -> Id {
+        self.id
+    }
+
+    fn parent(&self) -> Option<Id> {
+        self.parent
+    }
+
+    fn show(&mut self, ctx: &Context, focused: bool, dialogs: &mut DialogContext) -> Status {
+        let title = self.dialog.title();
+        let mut open = true;
+        let mut outcome = None;
+
+        if self.dialog.modal() {
+            Modal::new(self.id).show(ctx, |ui| {
+                ui.heading(title);
+                ui.separator();
+
+                outcome = self.dialog.show(ui);
+            });
+        } else {
+            Window::new(title)
+                .id(self.id)
+                .open(&mut open)
+                .collapsible(false)
+                .show(ctx, |ui| {
+                    outcome = self.dialog.show(ui);
+                });
+        }
+
+        if !open {
+            outcome = Some(DialogOutcome::Cancelled);
+        }
+
+        if focused && outcome.is_none() {
+            outcome = self.keyboard(ctx);
+        }
+
+        match outcome {
+            None => Status::Open,
+            Some(DialogOutcome::Cancelled) => Status::Closed,
+            Some(DialogOutcome::Submitted(output)) => {
+                let opened = dialogs.opened.len();
+
+                dialogs.current = Some(self.id);
+                (self.handler)(output, dialogs);
+                dialogs.current = None;
+
+                match dialogs.failure.take() {
+                    Some((context, error)) => {
+                        if let Some(api_error) = error.downcast_ref::<ApiError>()
+                            && self.dialog.apply_field_errors(&api_error.field_errors)
+                        {
+                            return Status::Open;
+                        }
+
+                        dialogs.notifications.error(&context, &error);
+
+                        Status::Closed
+                    }
+                    None if dialogs.opened.len() > opened => Status::Open,
+                    None => Status::Completed,
+                }
+            }
+        }
+    }
+}
+
+pub struct DialogStack {
+    entries: Vec<Box<dyn StackEntry>>,
+    next_id: u64,
+}
+
+impl DialogStack {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        !self.entries.is_empty()
+    }
+
+    pub fn push<D: Dialog + 'static>(
+        &mut self,
+        dialog: D,
+        handler: impl FnMut(D::Output, &mut DialogContext<'_>) + 'static,
+    ) {
+        self.next_id += 1;
+
+        self.entries.push(Box::new(Entry {
+            id: Id::new(("dialog", self.next_id)),
+            parent: None,
+            dialog,
+            handler: Box::new(handler),
+        }));
+    }
+
+    pub fn show(
+        &mut self,
+        ctx: &Context,
+        data_layer: &mut DataLayer,
+        notifications: &mut NotificationCenter,
+    ) {
+        let mut dialogs = DialogContext {
+            data_layer,
+            notifications,
+            current: None,
+            next_id: self.next_id,
+            opened: Vec::new(),
+            failure: None,
+        };
+
+        let focused = self.entries.last().map(|entry| entry.id());
+        let mut closed = Vec::new();
+
+        for entry in self.entries.iter_mut() {
+            match entry.show(ctx, Some(entry.id()) == focused, &mut dialogs) {
+                Status::Open => {}
+                Status::Closed => closed.push(entry.id()),
+                Status::Completed => {
+                    closed.push(entry.id());
+                    closed.extend(entry.parent());
+                }
+            }
+        }
+
+        self.next_id = dialogs.next_id;
+        self.entries.append(&mut dialogs.opened);
+
+        for entry in self.entries.iter() {
+            if let Some(parent) = entry.parent()
+                && closed.contains(&parent)
+            {
+                closed.push(entry.id());
+            }
+        }
+
+        self.entries.retain(|entry| !closed.contains(&entry.id()));
+    }
 }
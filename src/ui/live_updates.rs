@@ -0,0 +1,142 @@
+use crate::{
+    data_types::{FieldErrors, LiveTransport, LiveUpdateSettings},
+    t,
+    ui::{
+        accessibility::initial_focus,
+        dialog::{Dialog, DialogOutcome},
+        validation::{field_error, mark_field},
+    },
+};
+
+use egui::{Align, Checkbox, ComboBox, DragValue, Layout, Ui};
+
+pub struct LiveUpdatesDialog {
+    settings: LiveUpdateSettings,
+    errors: FieldErrors,
+    submitted: bool,
+}
+
+impl LiveUpdatesDialog {
+    pub fn new(settings: LiveUpdateSettings) -> Self {
+        Self {
+            settings,
+            errors: FieldErrors::new(),
+            submitted: false,
+        }
+    }
+
+    fn validate(&self) -> FieldErrors {
+        let mut errors = FieldErrors::new();
+
+        let endpoint = self.settings.endpoint.trim();
+
+        errors.require(
+            "endpoint",
+            !endpoint.is_empty(),
+            t!("validation-endpoint-required"),
+        );
+        errors.require(
+            "endpoint",
+            endpoint.is_empty() || endpoint.starts_with('/') || endpoint.contains("://"),
+            t!("validation-endpoint-format"),
+        );
+
+        errors
+    }
+}
+
+impl Dialog for LiveUpdatesDialog {
+    type Output = LiveUpdateSettings;
+
+    fn title(&self) -> String {
+        t!("live-updates-title")
+    }
+
+    fn show(&mut self, ui: &mut Ui) -> Option<DialogOutcome<LiveUpdateSettings>> {
+        let mut outcome = None;
+
+        ui.label(t!("live-updates-intro"));
+
+        let response = ui.add(Checkbox::new(
+            &mut self.settings.enabled,
+            t!("live-updates-enable"),
+        ));
+        initial_focus(&response);
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("live-updates-transport"));
+
+            ComboBox::from_id_salt("live_transport")
+                .selected_text(self.settings.transport.name())
+                .show_ui(ui, |ui| {
+                    for transport in [LiveTransport::ServerSentEvents, LiveTransport::WebSocket] {
+                        ui.selectable_value(
+                            &mut self.settings.transport,
+                            transport,
+                            transport.name(),
+                        );
+                    }
+                })
+                .response
+                .labelled_by(label.id);
+        });
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("live-updates-endpoint"));
+            let response = ui
+                .text_edit_singleline(&mut self.settings.endpoint)
+                .on_hover_text(t!("live-updates-endpoint-hint"))
+                .labelled_by(label.id);
+            mark_field(ui, &response, &self.errors, "endpoint");
+
+            if response.changed() && self.submitted {
+                self.errors = self.validate();
+            }
+        });
+        field_error(ui, &self.errors, "endpoint");
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("live-updates-reconnect"));
+            ui.add(
+                DragValue::new(&mut self.settings.reconnect_delay_secs)
+                    .range(1..=300)
+                    .suffix(t!("unit-seconds")),
+            )
+            .labelled_by(label.id);
+        });
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("live-updates-poll"));
+            ui.add(
+                DragValue::new(&mut self.settings.poll_interval_secs)
+                    .range(5..=3600)
+                    .suffix(t!("unit-seconds")),
+            )
+            .labelled_by(label.id);
+        });
+
+        ui.horizontal(|ui| {
+            ui.with_layout(Layout::right_to_left(Align::RIGHT), |ui| {
+                if ui.button(t!("button-save")).clicked() {
+                    outcome = self.submit().map(DialogOutcome::Submitted);
+                }
+
+                if ui.button(t!("button-cancel")).clicked() {
+                    outcome = Some(DialogOutcome::Cancelled);
+                }
+            });
+        });
+
+        outcome
+    }
+
+    fn submit(&mut self) -> Option<LiveUpdateSettings> {
+        self.submitted = true;
+        self.errors = self.validate();
+
+        self.errors.is_empty().then(|| LiveUpdateSettings {
+            endpoint: self.settings.endpoint.trim().to_string(),
+            ..self.settings.clone()
+        })
+    }
+}
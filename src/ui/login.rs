@@ -1,75 +1,140 @@
-use crate::{data_types::Credentials, ui::dialog::Dialog};
+use crate::{
+    data_types::{Credentials, FieldErrors},
+    t,
+    ui::{
+        accessibility::initial_focus,
+        dialog::{Dialog, DialogOutcome},
+        password::PasswordInput,
+        validation::{field_error, mark_field},
+    },
+};
 
-use egui::{Context, Align, Layout, Window};
-use std::any::Any;
+use egui::{Align, Layout, Ui};
 
 pub struct LoginDialog {
     creds: Credentials,
-    changed: bool,
+    requires_password: bool,
+    password_input: PasswordInput,
+    errors: FieldErrors,
+    submitted: bool,
 }
 
 impl Clone for LoginDialog {
     fn clone(&self) -> Self {
         Self {
             creds: self.creds.clone(),
-            changed: self.changed,
+            requires_password: self.requires_password,
+            password_input: self.password_input,
+            errors: self.errors.clone(),
+            submitted: self.submitted,
         }
     }
 }
 
 impl LoginDialog {
-    pub fn new(creds: Credentials) -> Self {
+    pub fn new(creds: Credentials, requires_password: bool) -> Self {
         Self {
             creds,
-            changed: false,
+            requires_password,
+            password_input: PasswordInput::new(),
+            errors: FieldErrors::new(),
+            submitted: false,
         }
     }
 
-    pub fn get_credentials(&self) -> Option<Credentials> {
-        if self.valid() {
-            Some(self.creds.clone())
-        } else {
-            None
+    fn validate(&self) -> FieldErrors {
+        let mut errors = FieldErrors::new();
+
+        if self.requires_password {
+            errors.require(
+                "username",
+                !self.creds.username.is_empty(),
+                t!("validation-username-required"),
+            );
+            errors.require(
+                "password",
+                !self.creds.password.is_empty(),
+                t!("validation-password-required"),
+            );
         }
-    }
 
-    fn valid(&self) -> bool {
-        !self.creds.username.is_empty() && !self.creds.password.is_empty()
+        errors
     }
 }
 
 impl Dialog for LoginDialog {
-    fn show(&mut self, ctx: &Context, open: &mut bool) {
-        Window::new("Login").open(open).show(ctx, |ui| {
-            ui.label("Login to your account");
+    type Output = Credentials;
+
+    fn title(&self) -> String {
+        t!("login-title")
+    }
+
+    fn show(&mut self, ui: &mut Ui) -> Option<DialogOutcome<Credentials>> {
+        let mut outcome = None;
+
+        if self.requires_password {
+            ui.label(t!("login-intro"));
+
+            let mut edited = false;
 
             ui.horizontal(|ui| {
-                ui.label("Username:");
-                ui.text_edit_singleline(&mut self.creds.username);
+                let label = ui.label(t!("label-username"));
+                let response = ui
+                    .text_edit_singleline(&mut self.creds.username)
+                    .labelled_by(label.id);
+                mark_field(ui, &response, &self.errors, "username");
+                initial_focus(&response);
+                edited |= response.changed();
             });
+            field_error(ui, &self.errors, "username");
 
             ui.horizontal(|ui| {
-                ui.label("Password:");
-                ui.text_edit_singleline(&mut self.creds.password);
+                let label = ui.label(t!("label-password"));
+                let response = self
+                    .password_input
+                    .show(ui, &mut self.creds.password)
+                    .labelled_by(label.id);
+                mark_field(ui, &response, &self.errors, "password");
+                edited |= response.changed();
             });
+            field_error(ui, &self.errors, "password");
 
-            ui.horizontal(|ui| {
-                ui.with_layout(Layout::right_to_left(Align::RIGHT), |ui| {
-                    if ui.button("Login").clicked() {
-                        if self.valid() {
-                            self.changed = true;
-                        }
-                    }
-                });
+            if edited && self.submitted {
+                self.errors = self.validate();
+            }
+        } else {
+            ui.label(t!("login-oauth-intro"));
+        }
+
+        ui.horizontal(|ui| {
+            ui.with_layout(Layout::right_to_left(Align::RIGHT), |ui| {
+                if ui.button(t!("login-button")).clicked() {
+                    outcome = self.submit().map(DialogOutcome::Submitted);
+                }
+
+                if ui.button(t!("button-cancel")).clicked() {
+                    outcome = Some(DialogOutcome::Cancelled);
+                }
             });
         });
+
+        outcome
     }
 
-    fn changed(&self) -> (bool, &str) {
-        (self.changed, "Login")
+    fn submit(&mut self) -> Option<Credentials> {
+        self.submitted = true;
+        self.errors = self.validate();
+
+        self.errors.is_empty().then(|| self.creds.clone())
     }
 
-    fn as_any(&self) -> &dyn Any {
-        self
+    fn apply_field_errors(&mut self, errors: &FieldErrors) -> bool {
+        if !errors.any_of(&["username", "password"]) {
+            return false;
+        }
+
+        self.errors = errors.clone();
+
+        true
     }
 }
@@ -1,398 +1,1279 @@
 use std::{
-    cell::{Ref, RefCell, RefMut},
-    collections::HashMap,
+    cell::RefCell,
+    cmp::Reverse,
+    path::Path,
+    sync::Arc,
+    time::{Duration, Instant},
 };
 
-use egui::Sense;
-use egui_extras::{Column, TableBuilder};
-use egui::{CentralPanel, Color32, Context};
-use eframe::App;
+use eframe::{APP_KEY, App, Storage};
+use egui::{
+    Align, Button, CentralPanel, Checkbox, Context, DragValue, KeyboardShortcut, Layout, OpenUrl,
+    RichText, SidePanel, Theme, TopBottomPanel, ViewportCommand, Window,
+};
 
 use crate::{
-    communication_layer::DataLayer,
-    config::Config,
-    data_types::{SessionState, Credentials, Movie, Role},
+    communication_layer::{DataLayer, PosterCache},
+    data_types::{
+        AppearanceSettings, Command, Language, LayoutState, LiveState, Movie, MovieTableSettings,
+        Permission, Role, SavedSearch, SessionState, ThemeMode,
+    },
+    i18n, t,
     ui::{
-        alert::Alert, create_movie::CreateMovieDialog, dialog::Dialog, login::LoginDialog,
+        accessibility::set_label,
+        account::{ChangePasswordDialog, DeleteAccountDialog},
+        appearance::{AppearanceDialog, apply_appearance},
+        command_palette::{CommandPalette, PaletteOutcome},
+        confirm::ConfirmDialog,
+        create_movie::CreateMovieDialog,
+        dialog::{DialogContext, DialogStack},
+        live_updates::LiveUpdatesDialog,
+        login::LoginDialog,
+        movie_details::show_movie_details,
+        movie_table::{MovieTable, MovieTableAction},
+        notifications::{NotificationAction, NotificationCenter},
+        proxy_settings::ProxySettingsDialog,
         register::RegisterDialog,
+        save_search::SaveSearchDialog,
+        shortcuts::{ShortcutsDialog, parse_shortcut},
+        users::{UserAction, UserManagementPanel},
     },
 };
 
-mod alert;
+mod accessibility;
+mod account;
+mod appearance;
+mod command_palette;
+mod confirm;
 mod create_movie;
 mod dialog;
+mod live_updates;
 mod login;
+mod movie_details;
+mod movie_table;
+mod notifications;
+mod password;
+mod proxy_settings;
 mod register;
+mod save_search;
+mod shortcuts;
+mod users;
+mod validation;
+
+pub const DEFAULT_WINDOW_SIZE: [f32; 2] = [800.0, 600.0];
+
+const UNDO_WINDOW: Duration = Duration::from_secs(8);
+const MIN_REFRESH_INTERVAL: u64 = 5;
+
+type ConfirmHandler = Box<dyn FnMut((), &mut DialogContext<'_>)>;
+
+fn upload_poster(id: Option<i32>, poster: Option<&Path>, dialogs: &mut DialogContext) {
+    let Some(path) = poster else {
+        return;
+    };
+
+    let Some(id) = id else {
+        dialogs.notifications.warning(t!("poster-missing-movie-id"));
+
+        return;
+    };
+
+    if let Err(error) = dialogs.data_layer.upload_poster(id, path) {
+        dialogs
+            .notifications
+            .error(&t!("poster-upload-failed"), &error);
+    }
+}
 
-type Callback<T> = dyn FnMut(Ref<Box<T>>, RefMut<DataLayer>, RefMut<Alert>);
+fn create_movie(movie: Movie, poster: Option<&Path>, dialogs: &mut DialogContext) {
+    let title = movie.title.clone();
+
+    match dialogs.data_layer.create_movie(movie) {
+        Ok(id) => {
+            dialogs
+                .notifications
+                .success(t!("movie-created", title = title));
+
+            upload_poster(id, poster, dialogs);
+        }
+        Err(error) => dialogs.fail(&t!("movie-create-failed"), error),
+    }
+}
 
 pub struct MainUi {
-    dialogs: Vec<RefCell<Box<dyn Dialog>>>,
+    dialogs: DialogStack,
     data_layer: RefCell<DataLayer>,
-    show_dialog: bool,
-    callbacks: HashMap<String, Box<Callback<dyn Dialog>>>,
     selected_movie_id: Option<i32>,
-    alert_box: RefCell<Alert>,
-    show_alert: bool,
+    notifications: RefCell<NotificationCenter>,
     server_url: String,
+    users_panel: UserManagementPanel,
+    movie_table: MovieTable,
+    posters: PosterCache,
+    command_palette: Option<CommandPalette>,
+    appearance: AppearanceSettings,
+    last_refresh: Instant,
+    auto_refresh_failed: bool,
+    live_poll_failed: bool,
 }
 
 impl MainUi {
-    pub fn new() -> Self {
+    pub fn new(ctx: &Context, storage: Option<&dyn Storage>) -> Self {
+        let layout: LayoutState = storage
+            .and_then(|storage| eframe::get_value(storage, APP_KEY))
+            .unwrap_or_default();
+
+        let mut data_layer = DataLayer::new();
+
+        let repaint_ctx = ctx.clone();
+        data_layer.on_session_changed(move |_| repaint_ctx.request_repaint());
+
+        let repaint_ctx = ctx.clone();
+        data_layer.on_live_update(move || repaint_ctx.request_repaint());
+
+        let _ = data_layer.refresh_identity();
+
+        egui_extras::install_image_loaders(ctx);
+
+        i18n::set_language(data_layer.config.language);
+
+        let appearance = data_layer.config.appearance.clone();
+        apply_appearance(ctx, &appearance);
+
+        let mut posters = PosterCache::new();
+
+        let repaint_ctx = ctx.clone();
+        posters.set_notifier(Arc::new(move || repaint_ctx.request_repaint()));
+
+        let users_panel_open = layout.users_panel_open && data_layer.can(Permission::ManageUsers);
+
+        if users_panel_open {
+            let _ = data_layer.list_users();
+        }
+
+        let mut notifications = NotificationCenter::new();
+        notifications.history_open = layout.notifications_open;
+
+        let mut users_panel = UserManagementPanel::new();
+        users_panel.open = users_panel_open;
+
+        let server_url = data_layer.config.base_url.clone();
+
         Self {
-            dialogs: Vec::new(),
-            data_layer: RefCell::new(DataLayer::new()),
-            show_dialog: false,
-            callbacks: HashMap::new(),
+            dialogs: DialogStack::new(),
+            data_layer: RefCell::new(data_layer),
             selected_movie_id: None,
-            alert_box: RefCell::new(Alert::new("Error".into())),
-            show_alert: false,
-            server_url: String::new(),
+            notifications: RefCell::new(notifications),
+            server_url,
+            users_panel,
+            movie_table: MovieTable::new(layout.movie_table),
+            posters,
+            command_palette: None,
+            appearance,
+            last_refresh: Instant::now(),
+            auto_refresh_failed: false,
+            live_poll_failed: false,
         }
     }
 
-    fn show_dialog(&mut self, dialog: Box<dyn Dialog>) {
-        if !self.show_dialog {
-            self.dialogs.clear();
-            self.dialogs.push(RefCell::new(dialog));
-            self.show_dialog = true;
+    fn show_pending_authorization(&mut self, ctx: &Context) {
+        let mut data_layer = self.data_layer.borrow_mut();
+
+        if let Some(url) = data_layer.authorization_url_to_open() {
+            ctx.open_url(OpenUrl::new_tab(url));
+        }
+
+        match data_layer.poll_authorization() {
+            Some(Ok(())) => {}
+            Some(Err(error)) => {
+                self.notifications
+                    .borrow_mut()
+                    .error(&t!("error-log-in"), &error);
+            }
+            None => {
+                let Some(url) = data_layer.pending_authorization_url() else {
+                    return;
+                };
+
+                let mut cancelled = false;
+
+                Window::new(t!("login-waiting-title")).show(ctx, |ui| {
+                    ui.label(t!("login-waiting-intro"));
+                    ui.hyperlink_to(t!("login-waiting-open-again"), url);
+
+                    if ui.button(t!("button-cancel")).clicked() {
+                        cancelled = true;
+                    }
+                });
+
+                if cancelled {
+                    data_layer.cancel_authorization();
+                } else {
+                    ctx.request_repaint_after(Duration::from_millis(200));
+                }
+            }
         }
     }
 
-    fn reset_dialog(&mut self) {
-        self.dialogs.clear();
-        self.show_dialog = false;
+    fn handle_user_action(&mut self, action: UserAction) {
+        let (title, message, handler): (String, String, ConfirmHandler) = match action {
+            UserAction::Refresh => {
+                self.data_layer
+                    .borrow_mut()
+                    .list_users()
+                    .unwrap_or_else(|error| {
+                        self.notifications
+                            .borrow_mut()
+                            .error(&t!("users-list-failed"), &error)
+                            .with_action(NotificationAction::ListUsers);
+                    });
+
+                return;
+            }
+            UserAction::ChangeRole(id, role) => {
+                let result = self.data_layer.borrow_mut().change_user_role(id, role);
+
+                match result {
+                    Ok(()) => {
+                        self.notifications.borrow_mut().success(t!(
+                            "users-role-changed",
+                            username = self.username_of(id),
+                            role = role.name()
+                        ));
+                    }
+                    Err(error) => {
+                        self.notifications
+                            .borrow_mut()
+                            .error(&t!("error-change-role"), &error);
+                    }
+                }
+
+                return;
+            }
+            UserAction::SetDisabled(id, false) => {
+                let result = self.data_layer.borrow_mut().set_user_disabled(id, false);
+
+                match result {
+                    Ok(()) => {
+                        self.notifications
+                            .borrow_mut()
+                            .success(t!("users-enabled", username = self.username_of(id)));
+                    }
+                    Err(error) => {
+                        self.notifications
+                            .borrow_mut()
+                            .error(&t!("users-enable-failed"), &error);
+                    }
+                }
+
+                return;
+            }
+            UserAction::SetDisabled(id, true) => (
+                t!("users-disable-title"),
+                t!("users-disable-confirm", username = self.username_of(id)),
+                Box::new(
+                    move |(), dialogs| match dialogs.data_layer.set_user_disabled(id, true) {
+                        Ok(()) => {
+                            dialogs.notifications.success(t!("users-disabled-success"));
+                        }
+                        Err(error) => dialogs.fail(&t!("users-disable-failed"), error),
+                    },
+                ),
+            ),
+            UserAction::ResetPassword(id, password) => (
+                t!("users-reset-password"),
+                t!(
+                    "users-reset-password-confirm",
+                    username = self.username_of(id)
+                ),
+                Box::new(move |(), dialogs| {
+                    match dialogs.data_layer.reset_user_password(id, password.clone()) {
+                        Ok(()) => {
+                            dialogs.notifications.success(t!("users-password-reset"));
+                        }
+                        Err(error) => dialogs.fail(&t!("error-reset-password"), error),
+                    }
+                }),
+            ),
+            UserAction::Delete(id) => (
+                t!("users-delete"),
+                t!("users-delete-confirm", username = self.username_of(id)),
+                Box::new(
+                    move |(), dialogs| match dialogs.data_layer.delete_user(id) {
+                        Ok(()) => {
+                            dialogs.notifications.success(t!("users-deleted"));
+                        }
+                        Err(error) => dialogs.fail(&t!("error-delete-user"), error),
+                    },
+                ),
+            ),
+        };
+
+        self.dialogs
+            .push(ConfirmDialog::new(title, message), handler);
+    }
+
+    fn show_login_dialog(&mut self) {
+        let dialog = {
+            let config = &self.data_layer.borrow().config;
+
+            LoginDialog::new(config.creds.clone(), config.login_requires_password())
+        };
+
+        self.dialogs.push(dialog, |credentials, dialogs| {
+            match dialogs
+                .data_layer
+                .login(credentials.username, credentials.password)
+            {
+                Ok(()) => {
+                    dialogs.notifications.success(t!("login-success"));
+                }
+                Err(error) => dialogs.fail(&t!("error-log-in"), error),
+            }
+        });
+    }
+
+    fn list_movies(&mut self) {
+        self.last_refresh = Instant::now();
+
+        self.data_layer
+            .borrow_mut()
+            .list_movies(None)
+            .unwrap_or_else(|error| {
+                self.notifications
+                    .borrow_mut()
+                    .error(&t!("movies-list-failed"), &error)
+                    .with_action(NotificationAction::ListMovies);
+            });
+    }
+
+    fn auto_refresh(&mut self, ctx: &Context) {
+        let settings = self.data_layer.borrow().config.auto_refresh.clone();
+
+        if !settings.enabled || self.data_layer.borrow().config.base_url.is_empty() {
+            return;
+        }
+
+        let interval = Duration::from_secs(settings.interval_secs.max(MIN_REFRESH_INTERVAL));
+        let elapsed = self.last_refresh.elapsed();
+
+        if elapsed < interval {
+            ctx.request_repaint_after(interval - elapsed);
+
+            return;
+        }
+
+        self.last_refresh = Instant::now();
+
+        let result = self.data_layer.borrow_mut().list_movies(None);
+
+        match result {
+            Ok(()) => self.auto_refresh_failed = false,
+            Err(error) => {
+                if !self.auto_refresh_failed {
+                    self.notifications
+                        .borrow_mut()
+                        .error(&t!("movies-refresh-failed"), &error)
+                        .with_action(NotificationAction::ListMovies);
+                }
+
+                self.auto_refresh_failed = true;
+            }
+        }
+
+        ctx.request_repaint_after(interval);
+    }
+
+    fn live_updates(&mut self, ctx: &Context) {
+        let result = self.data_layer.borrow_mut().poll_live_updates();
+
+        match result {
+            Some(Ok(())) => self.live_poll_failed = false,
+            Some(Err(error)) => {
+                if !self.live_poll_failed {
+                    self.notifications
+                        .borrow_mut()
+                        .error(&t!("movies-refresh-failed"), &error)
+                        .with_action(NotificationAction::ListMovies);
+                }
+
+                self.live_poll_failed = true;
+            }
+            None => {}
+        }
+
+        if self.data_layer.borrow().live_state() != LiveState::Off {
+            ctx.request_repaint_after(Duration::from_secs(1));
+        }
+    }
+
+    fn handle_notification_action(&mut self, action: NotificationAction) {
+        match action {
+            NotificationAction::LogIn => self.show_login_dialog(),
+            NotificationAction::ListMovies => self.list_movies(),
+            NotificationAction::ListUsers => self.handle_user_action(UserAction::Refresh),
+            NotificationAction::UndoDelete(movie) => self.restore_movie(movie),
+        }
+    }
+
+    fn confirm_delete_movie(&mut self) {
+        let Some(id) = self.selected_movie_id else {
+            self.notifications
+                .borrow_mut()
+                .warning(t!("movie-none-selected"));
+
+            return;
+        };
+
+        let Some(movie) = self
+            .data_layer
+            .borrow()
+            .movies
+            .iter()
+            .find(|movie| movie.id == id)
+            .cloned()
+        else {
+            self.notifications
+                .borrow_mut()
+                .warning(t!("error-no-movie-found"));
+
+            return;
+        };
+
+        let dialog = ConfirmDialog::new(
+            t!("movie-delete-title"),
+            t!(
+                "movie-delete-confirm",
+                title = movie.title.as_str(),
+                year = movie.release_year.to_string()
+            ),
+        );
+
+        self.dialogs.push(dialog, move |(), dialogs| {
+            match dialogs.data_layer.delete_movie(movie.id) {
+                Ok(()) => {
+                    dialogs
+                        .notifications
+                        .success(t!("movie-deleted", title = movie.title.as_str()))
+                        .with_timed_action(
+                            NotificationAction::UndoDelete(movie.clone()),
+                            UNDO_WINDOW,
+                        );
+                }
+                Err(error) => dialogs.fail(&t!("movie-delete-failed"), error),
+            }
+        });
+    }
+
+    fn restore_movie(&mut self, movie: Movie) {
+        let result = self.data_layer.borrow_mut().create_movie(movie.clone());
+        let mut notifications = self.notifications.borrow_mut();
+
+        match result {
+            Ok(Some(id)) if id != movie.id => {
+                notifications.warning(t!(
+                    "movie-restored-new-id",
+                    title = movie.title.as_str(),
+                    id = id.to_string(),
+                    old_id = movie.id.to_string()
+                ));
+
+                if self.selected_movie_id == Some(movie.id) {
+                    self.selected_movie_id = Some(id);
+                }
+            }
+            Ok(_) => {
+                notifications.success(t!("movie-restored", title = movie.title.as_str()));
+            }
+            Err(error) => {
+                notifications.error(&t!("movie-restore-failed"), &error);
+            }
+        }
+    }
+
+    fn show_register_dialog(&mut self) {
+        let dialog = {
+            let data_layer = self.data_layer.borrow();
+
+            RegisterDialog::new(
+                data_layer.can(Permission::RegisterAdmin),
+                data_layer.config.password_policy.clone(),
+            )
+        };
+        self.dialogs
+            .push(
+                dialog,
+                |(username, password, role), dialogs| match dialogs.data_layer.register(
+                    username.clone(),
+                    password,
+                    Role::from(role),
+                ) {
+                    Ok(()) => {
+                        dialogs
+                            .notifications
+                            .success(t!("register-success", username = username.as_str()));
+                    }
+                    Err(error) => dialogs.fail(&t!("error-register-user"), error),
+                },
+            );
+    }
+
+    fn logout(&mut self) {
+        match self.data_layer.borrow_mut().logout() {
+            Ok(()) => {
+                self.notifications.borrow_mut().info(t!("logout-success"));
+            }
+            Err(error) => {
+                self.notifications
+                    .borrow_mut()
+                    .error(&t!("error-log-out"), &error);
+            }
+        }
+    }
+
+    fn show_change_password_dialog(&mut self) {
+        let dialog = {
+            let config = &self.data_layer.borrow().config;
+
+            ChangePasswordDialog::new(
+                config.creds.username.clone(),
+                config.password_policy.clone(),
+            )
+        };
+        self.dialogs.push(
+            dialog,
+            |(old_password, new_password), dialogs| match dialogs
+                .data_layer
+                .change_password(old_password, new_password)
+            {
+                Ok(()) => {
+                    dialogs.notifications.success(t!("change-password-success"));
+                }
+                Err(error) => dialogs.fail(&t!("error-change-password"), error),
+            },
+        );
+    }
+
+    fn show_delete_account_dialog(&mut self) {
+        let dialog = DeleteAccountDialog::new();
+        self.dialogs.push(dialog, |password, dialogs| {
+            match dialogs.data_layer.delete_account(password) {
+                Ok(()) => {
+                    dialogs.notifications.success(t!("delete-account-success"));
+                }
+                Err(error) => dialogs.fail(&t!("error-delete-account"), error),
+            }
+        });
+    }
+
+    fn show_proxy_dialog(&mut self) {
+        let dialog = {
+            let data_layer = self.data_layer.borrow();
+
+            ProxySettingsDialog::new(
+                data_layer.config.clone(),
+                data_layer.client_error().map(String::from),
+            )
+        };
+
+        self.dialogs.push(dialog, |proxy, dialogs| {
+            dialogs.data_layer.config.proxy = proxy;
+            dialogs.data_layer.config.store_config();
+
+            match dialogs.data_layer.rebuild_client() {
+                Ok(()) => {
+                    dialogs.notifications.success(t!("proxy-applied"));
+                }
+                Err(error) => dialogs.fail(&t!("proxy-apply-failed"), error),
+            }
+        });
+    }
+
+    fn show_live_updates_dialog(&mut self) {
+        let dialog = LiveUpdatesDialog::new(self.data_layer.borrow().config.live_updates.clone());
+        self.dialogs.push(dialog, |settings, dialogs| {
+            dialogs.data_layer.config.live_updates = settings;
+            dialogs.data_layer.config.store_config();
+            dialogs.data_layer.restart_live_updates();
+        });
+    }
+
+    fn show_create_movie_dialog(&mut self) {
+        let dialog = CreateMovieDialog::new(None);
+        self.dialogs.push(dialog, |(movie, poster), dialogs| {
+            let duplicate = dialogs.data_layer.movies.iter().any(|existing| {
+                existing.title.eq_ignore_ascii_case(&movie.title)
+                    && existing.release_year == movie.release_year
+            });
+
+            if !duplicate {
+                create_movie(movie, poster.as_deref(), dialogs);
+
+                return;
+            }
+
+            let confirm = ConfirmDialog::new(
+                t!("movie-duplicate-title"),
+                t!(
+                    "movie-duplicate-confirm",
+                    title = movie.title.as_str(),
+                    year = movie.release_year.to_string()
+                ),
+            );
+
+            dialogs.open(confirm, move |(), dialogs| {
+                create_movie(movie.clone(), poster.as_deref(), dialogs)
+            });
+        });
+    }
+
+    fn show_update_movie_dialog(&mut self) {
+        let default_movie = Movie::default();
+
+        let dialog = CreateMovieDialog::new(Some(
+            self.data_layer
+                .borrow()
+                .movies
+                .iter()
+                .find(|&movie| {
+                    movie.id
+                        == self.selected_movie_id.unwrap_or_else(|| {
+                            self.notifications
+                                .borrow_mut()
+                                .warning(t!("movie-none-selected"));
+
+                            -1
+                        })
+                })
+                .unwrap_or_else(|| {
+                    if self.selected_movie_id.is_some() {
+                        self.notifications
+                            .borrow_mut()
+                            .warning(t!("error-no-movie-found"));
+                    }
+
+                    &default_movie
+                })
+                .clone(),
+        ));
+
+        self.dialogs.push(dialog, |(movie, poster), dialogs| {
+            let title = movie.title.clone();
+            let id = movie.id;
+
+            match dialogs.data_layer.update_movie(movie) {
+                Ok(()) => {
+                    dialogs
+                        .notifications
+                        .success(t!("movie-updated", title = title));
+
+                    upload_poster(Some(id), poster.as_deref(), dialogs);
+                }
+                Err(error) => dialogs.fail(&t!("movie-update-failed"), error),
+            }
+        });
+    }
+
+    fn show_appearance_dialog(&mut self) {
+        let dialog = AppearanceDialog::new(self.data_layer.borrow().config.appearance.clone());
+        self.dialogs.push(dialog, |settings, dialogs| {
+            dialogs.data_layer.config.appearance = settings;
+            dialogs.data_layer.config.store_config();
+        });
+    }
+
+    fn toggle_theme(&mut self, ctx: &Context) {
+        let mut data_layer = self.data_layer.borrow_mut();
+
+        data_layer.config.appearance.theme = match ctx.theme() {
+            Theme::Dark => ThemeMode::Light,
+            Theme::Light => ThemeMode::Dark,
+        };
+        data_layer.config.store_config();
+    }
+
+    fn update_appearance(&mut self, ctx: &Context) {
+        let mut data_layer = self.data_layer.borrow_mut();
+
+        if data_layer.config.appearance != self.appearance {
+            self.appearance = data_layer.config.appearance.clone();
+            apply_appearance(ctx, &self.appearance);
+
+            return;
+        }
+
+        let zoom = ctx.zoom_factor();
+
+        if (zoom - self.appearance.ui_scale).abs() > 0.001 {
+            self.appearance.ui_scale = zoom;
+            data_layer.config.appearance.ui_scale = zoom;
+            data_layer.config.store_config();
+        }
+    }
 
-        self.callbacks.clear();
+    fn show_shortcuts_dialog(&mut self) {
+        let dialog = ShortcutsDialog::new(&self.data_layer.borrow().config.key_bindings);
+        self.dialogs.push(dialog, |bindings, dialogs| {
+            dialogs.data_layer.config.key_bindings = bindings;
+            dialogs.data_layer.config.store_config();
+            dialogs.notifications.success(t!("shortcuts-saved"));
+        });
+    }
+
+    fn command_enabled(&self, command: Command) -> bool {
+        let data_layer = self.data_layer.borrow();
+        let authenticated = data_layer.session_state() != SessionState::Unauthenticated;
+
+        match command {
+            Command::Login => !authenticated,
+            Command::Logout | Command::ChangePassword | Command::DeleteAccount => authenticated,
+            Command::ManageUsers => data_layer.can(Permission::ManageUsers),
+            Command::CreateMovie => data_layer.can(Permission::CreateMovie),
+            Command::UpdateMovie => data_layer.can(Permission::UpdateMovie),
+            Command::DeleteMovie => data_layer.can(Permission::DeleteMovie),
+            _ => true,
+        }
+    }
+
+    fn execute(&mut self, ctx: &Context, command: Command) {
+        if !self.command_enabled(command) {
+            return;
+        }
+
+        match command {
+            Command::OpenPalette => self.command_palette = Some(CommandPalette::new()),
+            Command::CreateMovie => self.show_create_movie_dialog(),
+            Command::ListMovies => self.list_movies(),
+            Command::UpdateMovie => self.show_update_movie_dialog(),
+            Command::DeleteMovie => self.confirm_delete_movie(),
+            Command::ClearSelection => self.selected_movie_id = None,
+            Command::SelectPrevious | Command::SelectNext => {
+                let offset = if command == Command::SelectNext {
+                    1
+                } else {
+                    -1
+                };
+
+                self.movie_table.move_selection(
+                    &self.data_layer.borrow().movies,
+                    &mut self.selected_movie_id,
+                    offset,
+                );
+            }
+            Command::FocusSearch => self.movie_table.focus_search(),
+            Command::Register => self.show_register_dialog(),
+            Command::Login => self.show_login_dialog(),
+            Command::Logout => self.logout(),
+            Command::ChangePassword => self.show_change_password_dialog(),
+            Command::DeleteAccount => self.show_delete_account_dialog(),
+            Command::ManageUsers => {
+                self.users_panel.open = true;
+
+                self.handle_user_action(UserAction::Refresh);
+            }
+            Command::ProxySettings => self.show_proxy_dialog(),
+            Command::LiveUpdates => self.show_live_updates_dialog(),
+            Command::Appearance => self.show_appearance_dialog(),
+            Command::ToggleTheme => self.toggle_theme(ctx),
+            Command::KeyboardShortcuts => self.show_shortcuts_dialog(),
+            Command::ToggleNotifications => {
+                let mut notifications = self.notifications.borrow_mut();
+
+                notifications.history_open = !notifications.history_open;
+            }
+            Command::ResetLayout => self.reset_layout(ctx),
+        }
+    }
+
+    fn handle_shortcuts(&mut self, ctx: &Context) {
+        if self.dialogs.is_open() || self.command_palette.is_some() {
+            return;
+        }
+
+        let typing = ctx.wants_keyboard_input();
+
+        let mut shortcuts: Vec<(Command, KeyboardShortcut)> = {
+            let bindings = &self.data_layer.borrow().config.key_bindings;
+
+            Command::ALL
+                .into_iter()
+                .filter_map(|command| {
+                    parse_shortcut(bindings.get(command))
+                        .ok()
+                        .flatten()
+                        .map(|shortcut| (command, shortcut))
+                })
+                .filter(|(_, shortcut)| !typing || shortcut.modifiers.command)
+                .filter(|(command, _)| {
+                    self.selected_movie_id.is_some()
+                        || !matches!(command, Command::UpdateMovie | Command::DeleteMovie)
+                })
+                .collect()
+        };
+
+        shortcuts.sort_by_key(|(_, shortcut)| {
+            let modifiers = shortcut.modifiers;
+
+            Reverse(modifiers.command as u8 + modifiers.shift as u8 + modifiers.alt as u8)
+        });
+
+        let command = shortcuts.into_iter().find_map(|(command, shortcut)| {
+            ctx.input_mut(|input| input.consume_shortcut(&shortcut))
+                .then_some(command)
+        });
+
+        if let Some(command) = command {
+            self.execute(ctx, command);
+        }
+    }
+
+    fn show_command_palette(&mut self, ctx: &Context) {
+        if self.command_palette.is_none() {
+            return;
+        }
+
+        let commands: Vec<(Command, String)> = Command::ALL
+            .into_iter()
+            .filter(|&command| command != Command::OpenPalette && self.command_enabled(command))
+            .map(|command| {
+                let bindings = &self.data_layer.borrow().config.key_bindings;
+
+                (command, bindings.get(command).to_string())
+            })
+            .collect();
+
+        let Some(palette) = self.command_palette.as_mut() else {
+            return;
+        };
+
+        let Some(outcome) = palette.show(ctx, &commands) else {
+            return;
+        };
+
+        self.command_palette = None;
+
+        if let PaletteOutcome::Run(command) = outcome {
+            self.execute(ctx, command);
+        }
+    }
+
+    fn update_posters(&mut self, ctx: &Context) {
+        let data_layer = &mut self.data_layer.borrow_mut();
+
+        for poster_url in data_layer.take_uploaded_posters() {
+            self.posters.invalidate(data_layer, &poster_url);
+        }
+
+        self.posters.poll();
+
+        for uri in self.posters.take_released() {
+            ctx.forget_image(&uri);
+        }
+    }
+
+    fn handle_movie_table_action(&mut self, action: MovieTableAction) {
+        match action {
+            MovieTableAction::SaveSearch(query) => {
+                let existing = self
+                    .data_layer
+                    .borrow()
+                    .config
+                    .saved_searches
+                    .iter()
+                    .map(|search| search.name.clone())
+                    .collect();
+
+                let dialog = SaveSearchDialog::new(query.clone(), existing);
+                self.dialogs.push(dialog, move |name, dialogs| {
+                    let searches = &mut dialogs.data_layer.config.saved_searches;
+
+                    searches.retain(|search| search.name != name);
+                    searches.push(SavedSearch {
+                        name: name.clone(),
+                        query: query.clone(),
+                    });
+                    searches.sort_by_key(|search| search.name.to_lowercase());
+
+                    dialogs.data_layer.config.store_config();
+                    dialogs
+                        .notifications
+                        .success(t!("search-saved-success", name = name.as_str()));
+                });
+            }
+            MovieTableAction::DeleteSearch(name) => {
+                let mut data_layer = self.data_layer.borrow_mut();
+
+                data_layer
+                    .config
+                    .saved_searches
+                    .retain(|search| search.name != name);
+                data_layer.config.store_config();
+            }
+        }
+    }
+
+    fn reset_layout(&mut self, ctx: &Context) {
+        self.movie_table.settings = MovieTableSettings::default();
+        self.users_panel.open = false;
+        self.notifications.borrow_mut().history_open = false;
+
+        ctx.memory_mut(|memory| {
+            memory.reset_areas();
+            memory.data.clear();
+        });
+        ctx.send_viewport_cmd(ViewportCommand::InnerSize(DEFAULT_WINDOW_SIZE.into()));
+
+        self.notifications.borrow_mut().info(t!("layout-reset"));
+    }
+
+    fn set_language(&mut self, language: Language) {
+        let mut data_layer = self.data_layer.borrow_mut();
+
+        data_layer.config.language = language;
+        data_layer.config.store_config();
+
+        i18n::set_language(language);
+    }
+
+    fn username_of(&self, id: i32) -> String {
+        self.data_layer
+            .borrow()
+            .users
+            .iter()
+            .find(|user| user.id == id)
+            .map(|user| user.username.clone())
+            .unwrap_or_default()
     }
 }
 
 impl App for MainUi {
+    fn save(&mut self, storage: &mut dyn Storage) {
+        let layout = LayoutState {
+            movie_table: self.movie_table.settings.clone(),
+            users_panel_open: self.users_panel.open,
+            notifications_open: self.notifications.borrow().history_open,
+        };
+
+        eframe::set_value(storage, APP_KEY, &layout);
+    }
+
     fn update(&mut self, ctx: &Context, _: &mut eframe::Frame) {
-        if !self.data_layer.borrow().config.base_url.is_empty() && self.server_url.is_empty() {
-            self.server_url = self.data_layer.borrow().config.base_url.clone();
+        self.show_pending_authorization(ctx);
+        self.auto_refresh(ctx);
+        self.live_updates(ctx);
+        self.update_posters(ctx);
+        self.update_appearance(ctx);
+        self.handle_shortcuts(ctx);
+
+        if let Some(id) = self.selected_movie_id
+            && !self
+                .data_layer
+                .borrow()
+                .movies
+                .iter()
+                .any(|movie| movie.id == id)
+        {
+            self.selected_movie_id = None;
         }
 
-        CentralPanel::default()
-            .frame(egui::Frame {
-                fill: Color32::from_white_alpha(255),
-                ..Default::default()
-            })
-            .show(ctx, |ui| {
-                ui.vertical(|ui| {
+        TopBottomPanel::bottom("status_bar").show(ctx, |ui| {
+            let data_layer = self.data_layer.borrow();
+
+            ui.horizontal(|ui| {
+                if data_layer.config.tls.accept_invalid_certs {
+                    ui.label(
+                        RichText::new(t!("status-tls-disabled"))
+                            .color(ui.visuals().error_fg_color)
+                            .strong(),
+                    );
+                }
+
+                ui.label(t!("status-tls", summary = data_layer.config.tls.summary()));
+
+                if let Some(error) = data_layer.client_error() {
+                    ui.label(RichText::new(error).color(ui.visuals().error_fg_color));
+                }
+
+                let live_state = data_layer.live_state();
+
+                if live_state != LiveState::Off {
+                    let response = ui.label(t!("status-live", state = live_state.name()));
+
+                    if let Some(error) = data_layer.live_error() {
+                        response.on_hover_text(error);
+                    }
+                }
+
+                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                    let mut notifications = self.notifications.borrow_mut();
+
+                    let label = match notifications.unread() {
+                        0 => t!("notifications-title"),
+                        unread => t!("notifications-unread", count = unread),
+                    };
+
+                    if ui.button(label).clicked() {
+                        notifications.history_open = !notifications.history_open;
+                    }
+                });
+            });
+        });
+
+        if let Some(movie) = self.selected_movie_id.and_then(|id| {
+            self.data_layer
+                .borrow()
+                .movies
+                .iter()
+                .find(|movie| movie.id == id)
+                .cloned()
+        }) {
+            SidePanel::right("movie_details")
+                .resizable(true)
+                .default_width(250.0)
+                .show(ctx, |ui| {
+                    show_movie_details(ui, &movie, &self.data_layer.borrow(), &mut self.posters);
+                });
+        }
+
+        CentralPanel::default().show(ctx, |ui| {
+            ui.vertical(|ui| {
+                ui.add_space(5.0);
+
+                ui.horizontal(|ui| {
                     ui.add_space(5.0);
 
-                    ui.horizontal(|ui| {
-                        ui.add_space(5.0);
+                    let label = ui.label(t!("label-server-url"));
+                    let response = ui
+                        .text_edit_singleline(&mut self.server_url)
+                        .highlight()
+                        .labelled_by(label.id);
 
-                        ui.label("Server URL:");
-                        if ui
-                            .text_edit_singleline(&mut self.server_url)
-                            .highlight()
-                            .lost_focus()
-                        {
-                            self.data_layer.borrow_mut().config.base_url = self.server_url.clone();
-                            self.data_layer.borrow_mut().config.store_config();
-                        }
+                    if response.changed() {
+                        self.data_layer.borrow_mut().config.base_url = self.server_url.clone();
+                    }
+
+                    if response.lost_focus() {
+                        self.data_layer.borrow_mut().config.store_config();
+                    }
 
-                        if ui.button("Register").clicked() {
-                            let dialog = RegisterDialog::new();
-                            self.show_dialog(Box::new(dialog));
-
-                            self.callbacks.insert(
-                                "Register".to_string(),
-                                Box::new(|dialog, data_layer, mut alert_box| {
-                                    let register_dialog =
-                                        dialog.as_any().downcast_ref::<RegisterDialog>().unwrap();
-
-                                    let (username, password, role) =
-                                        register_dialog.get_data().unwrap_or_else(|| {
-                                            alert_box.message =
-                                                String::from("Failed to register user");
-
-                                            (String::new(), String::new(), String::new())
-                                        });
-
-                                    data_layer
-                                        .register(username, password, Role::from(role))
-                                        .unwrap_or_else(|error| {
-                                            alert_box.message =
-                                                String::from("Failed to register user: ")
-                                                    + &error.to_string();
-                                        });
-                                }),
-                            );
+                    if ui.button(t!("menu-register")).clicked() {
+                        self.execute(ui.ctx(), Command::Register);
+                    }
+
+                    if self.data_layer.borrow().session_state() == SessionState::Unauthenticated {
+                        if ui.button(t!("login-button")).clicked() {
+                            self.show_login_dialog();
+                        }
+                    } else {
+                        if ui.button(t!("menu-logout")).clicked() {
+                            self.execute(ui.ctx(), Command::Logout);
                         }
 
-                        if Config::load_config().current_session_state()
-                            == SessionState::Unauthenticated
-                        {
-                            if ui.button("Login").clicked() {
-                                let dialog =
-                                    LoginDialog::new(self.data_layer.borrow().config.creds.clone());
-                                self.show_dialog(Box::new(dialog));
-
-                                self.callbacks.insert(
-                                    "Login".to_string(),
-                                    Box::new(|dialog, mut data_layer, mut alert_box| {
-                                        let login_dialog =
-                                            dialog.as_any().downcast_ref::<LoginDialog>().unwrap();
-
-                                        let credentials =
-                                            login_dialog.get_credentials().unwrap_or_else(|| {
-                                                alert_box.message =
-                                                    String::from("Failed to log in");
-
-                                                Credentials::default()
-                                            });
-
-                                        data_layer
-                                            .login(credentials.username, credentials.password)
-                                            .unwrap_or_else(|error| {
-                                                alert_box.message =
-                                                    String::from("Failed to log in: ")
-                                                        + &error.to_string();
-                                            });
-                                    }),
-                                );
+                        ui.menu_button(t!("menu-account"), |ui| {
+                            if ui.button(t!("change-password-title")).clicked() {
+                                ui.close_menu();
+
+                                self.execute(ui.ctx(), Command::ChangePassword);
                             }
-                        } else {
-                            if ui.button("Logout").clicked() {
-                                self.data_layer
-                                    .borrow_mut()
-                                    .logout()
-                                    .unwrap_or_else(|error| {
-                                        self.alert_box.borrow_mut().message =
-                                            String::from("Failed to log out: ")
-                                                + &error.to_string();
-                                    });
+
+                            if ui.button(t!("delete-account-title")).clicked() {
+                                ui.close_menu();
+
+                                self.execute(ui.ctx(), Command::DeleteAccount);
                             }
-                        }
-                    });
+                        });
+                    }
 
-                    ui.add_space(5.0);
+                    if ui
+                        .add_enabled(
+                            self.data_layer.borrow().can(Permission::ManageUsers),
+                            Button::new(t!("menu-users")),
+                        )
+                        .clicked()
+                    {
+                        self.execute(ui.ctx(), Command::ManageUsers);
+                    }
 
-                    ui.horizontal(|ui| {
-                        ui.add_space(5.0);
+                    if ui.button(t!("menu-proxy")).clicked() {
+                        self.execute(ui.ctx(), Command::ProxySettings);
+                    }
 
-                        if ui.button("Create Movie").clicked() {
-                            let dialog = CreateMovieDialog::new(None);
-                            self.show_dialog(Box::new(dialog));
-
-                            self.callbacks.insert(
-                                "Create Movie".to_string(),
-                                Box::new(|dialog, mut data_layer, mut alert_box| {
-                                    let create_movie_dialog = dialog
-                                        .as_any()
-                                        .downcast_ref::<CreateMovieDialog>()
-                                        .unwrap();
-
-                                    let movie =
-                                        create_movie_dialog.get_movie().unwrap_or_else(|| {
-                                            alert_box.message =
-                                                String::from("Failed to create a movie: ");
-
-                                            Movie::default()
-                                        });
-
-                                    data_layer.create_movie(movie).unwrap_or_else(|error| {
-                                        alert_box.message =
-                                            String::from("Failed to create a movie: ")
-                                                + &error.to_string();
-                                    });
-                                }),
-                            );
-                        }
+                    if ui.button(t!("menu-live")).clicked() {
+                        self.execute(ui.ctx(), Command::LiveUpdates);
+                    }
 
-                        if ui.button("List Movies").clicked() {
-                            self.data_layer
-                                .borrow_mut()
-                                .list_movies(None)
-                                .unwrap_or_else(|error| {
-                                    self.alert_box.borrow_mut().message =
-                                        String::from("Failed to list movies: ")
-                                            + &error.to_string();
-
-                                    vec![Movie::default()]
-                                });
+                    ui.menu_button(t!("menu-view"), |ui| {
+                        if ui.button(t!("command-open-palette")).clicked() {
+                            ui.close_menu();
+
+                            self.execute(ui.ctx(), Command::OpenPalette);
                         }
 
-                        let default_movie = Movie::default();
-
-                        if ui.button("Update Movie").clicked() {
-                            let dialog = CreateMovieDialog::new(Some(
-                                self.data_layer
-                                    .borrow()
-                                    .movies
-                                    .iter()
-                                    .find(|&movie| {
-                                        movie.id
-                                            == self.selected_movie_id.unwrap_or_else(|| {
-                                                self.alert_box.borrow_mut().message =
-                                                    String::from("No movie selected");
-
-                                                -1
-                                            })
-                                    })
-                                    .unwrap_or_else(|| {
-                                        if self.selected_movie_id.is_some() {
-                                            self.alert_box.borrow_mut().message =
-                                                String::from("No movie found");
-                                        }
-
-                                        &default_movie
-                                    })
-                                    .clone(),
-                            ));
-
-                            self.show_dialog(Box::new(dialog));
-
-                            self.callbacks.insert(
-                                "Update Movie".to_string(),
-                                Box::new(|dialog, mut data_layer, mut alert_box| {
-                                    let update_movie_dialog = dialog
-                                        .as_any()
-                                        .downcast_ref::<CreateMovieDialog>()
-                                        .unwrap();
-
-                                    let movie = update_movie_dialog.get_movie().unwrap();
-
-                                    data_layer.update_movie(movie).unwrap_or_else(|error| {
-                                        alert_box.message =
-                                            String::from("Failed to update a movie: ")
-                                                + &error.to_string();
-                                    });
-                                }),
-                            );
+                        if ui.button(t!("appearance-title")).clicked() {
+                            ui.close_menu();
+
+                            self.execute(ui.ctx(), Command::Appearance);
                         }
 
-                        if ui.button("Delete Movie").clicked() {
-                            self.data_layer
-                                .borrow_mut()
-                                .delete_movie(self.selected_movie_id.unwrap_or_else(|| {
-                                    self.alert_box.borrow_mut().message =
-                                        String::from("No movie selected");
-
-                                    -1
-                                }))
-                                .unwrap_or_else(|error| {
-                                    self.alert_box.borrow_mut().message =
-                                        String::from("Failed to delete a movie: ")
-                                            + &error.to_string();
-                                });
+                        if ui.button(t!("command-keyboard-shortcuts")).clicked() {
+                            ui.close_menu();
+
+                            self.execute(ui.ctx(), Command::KeyboardShortcuts);
                         }
 
-                        if ui.button("Clear Selection").clicked() {
-                            self.selected_movie_id = None;
+                        if ui.button(t!("menu-reset-layout")).clicked() {
+                            ui.close_menu();
+
+                            self.execute(ui.ctx(), Command::ResetLayout);
                         }
-                    });
 
-                    ui.add_space(10.0);
+                        ui.menu_button(t!("menu-language"), |ui| {
+                            let current = self.data_layer.borrow().config.language;
 
-                    ui.horizontal(|ui| {
-                        ui.add_space(5.0);
+                            for language in Language::ALL {
+                                if ui.radio(current == language, language.name()).clicked() {
+                                    ui.close_menu();
 
-                        ui.vertical(|ui| {
-                            TableBuilder::new(ui)
-                                .animate_scrolling(true)
-                                .striped(true)
-                                .column(Column::initial(150.0).at_least(100.0))
-                                .column(Column::remainder())
-                                .column(Column::initial(100.0).at_least(100.0))
-                                .sense(Sense::click())
-                                .auto_shrink(false)
-                                .resizable(false)
-                                .header(10.0, |mut header| {
-                                    header.col(|ui| {
-                                        ui.label("Movie Name");
-                                    });
-                                    header.col(|ui| {
-                                        ui.label("Description");
-                                    });
-                                    header.col(|ui| {
-                                        ui.label("Release Year");
-                                    });
-                                })
-                                .body(|body| {
-                                    let num_movies = self.data_layer.borrow().movies.len();
-
-                                    body.rows(10.0, num_movies, |mut row| {
-                                        let index = row.index();
-
-                                        row.set_selected(
-                                            self.selected_movie_id
-                                                == Some(self.data_layer.borrow().movies[index].id),
-                                        );
-
-                                        row.col(|ui| {
-                                            ui.label(&self.data_layer.borrow().movies[index].title);
-                                        });
-                                        row.col(|ui| {
-                                            ui.label(
-                                                &self.data_layer.borrow().movies[index].description,
-                                            );
-                                        });
-                                        row.col(|ui| {
-                                            ui.label(
-                                                &self.data_layer.borrow().movies[index]
-                                                    .release_year
-                                                    .to_string(),
-                                            );
-                                        });
-
-                                        if row.response().clicked() {
-                                            self.selected_movie_id =
-                                                Some(self.data_layer.borrow().movies[index].id);
-                                        }
-                                    });
-                                });
+                                    self.set_language(language);
+                                }
+                            }
                         });
                     });
                 });
 
-                let mut handled_callback = false;
+                if let Some(identity) = self.data_layer.borrow().identity() {
+                    ui.horizontal(|ui| {
+                        ui.add_space(5.0);
 
-                for dialog in self.dialogs.iter_mut() {
-                    let changed: bool;
-                    let dialog_name: String;
+                        ui.label(t!(
+                            "status-logged-in",
+                            username = identity.username.as_str(),
+                            role = identity.role.name()
+                        ));
+                    });
+                }
+
+                ui.add_space(5.0);
+
+                ui.horizontal(|ui| {
+                    ui.add_space(5.0);
 
+                    if ui
+                        .add_enabled(
+                            self.data_layer.borrow().can(Permission::CreateMovie),
+                            Button::new(t!("movie-create-title")),
+                        )
+                        .clicked()
                     {
-                        let mut dlg = dialog.borrow_mut();
+                        self.execute(ui.ctx(), Command::CreateMovie);
+                    }
+
+                    if ui.button(t!("menu-list-movies")).clicked() {
+                        self.execute(ui.ctx(), Command::ListMovies);
+                    }
 
-                        dlg.show(ctx, &mut self.show_dialog);
+                    if ui
+                        .add_enabled(
+                            self.data_layer.borrow().can(Permission::UpdateMovie),
+                            Button::new(t!("movie-update-title")),
+                        )
+                        .clicked()
+                    {
+                        self.execute(ui.ctx(), Command::UpdateMovie);
+                    }
 
-                        let (ch, dlg_name) = dlg.changed();
+                    if ui
+                        .add_enabled(
+                            self.data_layer.borrow().can(Permission::DeleteMovie),
+                            Button::new(t!("movie-delete-title")),
+                        )
+                        .clicked()
+                    {
+                        self.execute(ui.ctx(), Command::DeleteMovie);
+                    }
 
-                        changed = ch;
-                        dialog_name = dlg_name.into();
+                    if ui.button(t!("menu-clear-selection")).clicked() {
+                        self.execute(ui.ctx(), Command::ClearSelection);
                     }
 
-                    if changed {
-                        self.callbacks.get_mut(&dialog_name).map(|callback| {
-                            callback(
-                                dialog.borrow(),
-                                self.data_layer.borrow_mut(),
-                                self.alert_box.borrow_mut(),
-                            )
-                        });
+                    ui.separator();
 
-                        handled_callback = true;
+                    let mut data_layer = self.data_layer.borrow_mut();
+                    let settings = &mut data_layer.config.auto_refresh;
+
+                    let mut changed = ui
+                        .add(Checkbox::new(&mut settings.enabled, t!("auto-refresh")))
+                        .changed();
+
+                    let response = ui.add_enabled(
+                        settings.enabled,
+                        DragValue::new(&mut settings.interval_secs)
+                            .range(MIN_REFRESH_INTERVAL..=3600)
+                            .prefix(t!("auto-refresh-every"))
+                            .suffix(t!("unit-seconds")),
+                    );
+                    set_label(&response, t!("a11y-auto-refresh-interval"));
+                    changed |= response.drag_stopped()
+                        || response.lost_focus()
+                        || (response.changed() && !response.dragged() && !response.has_focus());
+
+                    if changed {
+                        data_layer.config.store_config();
                     }
-                }
+                });
 
-                if handled_callback {
-                    self.reset_dialog();
-                }
+                ui.add_space(10.0);
 
-                let (changed, _) = self.alert_box.borrow().changed();
+                ui.horizontal(|ui| {
+                    ui.add_space(5.0);
 
-                self.show_alert = changed;
+                    ui.vertical(|ui| {
+                        let action = {
+                            let data_layer = self.data_layer.borrow();
 
-                if self.show_alert {
-                    self.show_dialog = false;
+                            self.movie_table.show(
+                                ui,
+                                &data_layer,
+                                &mut self.posters,
+                                &mut self.selected_movie_id,
+                            )
+                        };
 
-                    self.alert_box.borrow_mut().show(ctx, &mut self.show_alert);
-                }
+                        if let Some(action) = action {
+                            self.handle_movie_table_action(action);
+                        }
+                    });
+                });
             });
+
+            self.dialogs.show(
+                ctx,
+                &mut self.data_layer.borrow_mut(),
+                &mut self.notifications.borrow_mut(),
+            );
+        });
+
+        if self.users_panel.open && !self.data_layer.borrow().can(Permission::ManageUsers) {
+            self.users_panel.open = false;
+        }
+
+        if self.users_panel.open {
+            let action = self.users_panel.show(ctx, &self.data_layer.borrow().users);
+
+            if let Some(action) = action {
+                self.handle_user_action(action);
+            }
+        }
+
+        let mut action = self.notifications.borrow_mut().show_toasts(ctx);
+
+        if self.notifications.borrow().history_open {
+            action = action.or(self.notifications.borrow_mut().show_history(ctx));
+        }
+
+        if let Some(action) = action {
+            self.handle_notification_action(action);
+        }
+
+        self.show_command_palette(ctx);
     }
 }
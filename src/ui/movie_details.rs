@@ -0,0 +1,129 @@
+use crate::{
+    communication_layer::{DataLayer, Poster, PosterCache},
+    data_types::Movie,
+    t,
+};
+
+use egui::{CollapsingHeader, Grid, Image, Label, ScrollArea, Ui, Vec2, load::Bytes};
+use serde_json::{Map, Value};
+
+pub fn extras_grid(ui: &mut Ui, extras: &Map<String, Value>) {
+    Grid::new("movie_extras")
+        .num_columns(2)
+        .striped(true)
+        .show(ui, |ui| {
+            for (key, value) in extras {
+                let text = match value {
+                    Value::String(text) => text.clone(),
+                    other => other.to_string(),
+                };
+
+                let label = ui.label(key);
+                ui.add(Label::new(text).wrap()).labelled_by(label.id);
+                ui.end_row();
+            }
+        });
+}
+
+pub fn poster_image(ui: &mut Ui, poster: &Poster, max_size: Vec2, title: &str) {
+    match poster {
+        Poster::Loading => {
+            ui.spinner();
+        }
+        Poster::Ready { uri, bytes } => {
+            ui.add(
+                Image::from_bytes(uri.clone(), Bytes::Shared(bytes.clone()))
+                    .max_size(max_size)
+                    .maintain_aspect_ratio(true)
+                    .alt_text(t!("a11y-poster", title = title)),
+            );
+        }
+        Poster::Unavailable => {}
+    }
+}
+
+pub fn show_movie_details(
+    ui: &mut Ui,
+    movie: &Movie,
+    data_layer: &DataLayer,
+    posters: &mut PosterCache,
+) {
+    ui.heading(&movie.title);
+    ui.separator();
+
+    ScrollArea::vertical().auto_shrink(false).show(ui, |ui| {
+        if let Some(poster_url) = &movie.poster_url {
+            let poster = posters.get(data_layer, poster_url);
+
+            if let Poster::Unavailable = poster {
+                ui.label(t!("movie-poster-unavailable"));
+            } else {
+                poster_image(
+                    ui,
+                    poster,
+                    Vec2::new(ui.available_width(), f32::INFINITY),
+                    &movie.title,
+                );
+            }
+
+            ui.add_space(5.0);
+        }
+
+        Grid::new("movie_details")
+            .num_columns(2)
+            .striped(true)
+            .show(ui, |ui| {
+                let label = ui.label(t!("movie-release-year"));
+                ui.label(movie.release_year.to_string())
+                    .labelled_by(label.id);
+                ui.end_row();
+
+                if let Some(genres) = &movie.genres {
+                    let label = ui.label(t!("movie-genres"));
+                    ui.add(Label::new(genres.join(", ")).wrap())
+                        .labelled_by(label.id);
+                    ui.end_row();
+                }
+
+                if let Some(director) = &movie.director {
+                    let label = ui.label(t!("movie-director"));
+                    ui.label(director).labelled_by(label.id);
+                    ui.end_row();
+                }
+
+                if let Some(rating) = movie.rating {
+                    let label = ui.label(t!("movie-rating"));
+                    ui.label(t!("movie-rating-value", rating = rating))
+                        .labelled_by(label.id);
+                    ui.end_row();
+                }
+
+                if let Some(runtime) = movie.runtime {
+                    let label = ui.label(t!("movie-runtime"));
+                    ui.label(t!("movie-runtime-value", runtime = runtime))
+                        .labelled_by(label.id);
+                    ui.end_row();
+                }
+
+                if let Some(poster_url) = &movie.poster_url {
+                    let label = ui.label(t!("movie-poster"));
+                    ui.hyperlink_to(t!("movie-poster-open"), data_layer.resolve_url(poster_url))
+                        .labelled_by(label.id);
+                    ui.end_row();
+                }
+            });
+
+        ui.add_space(5.0);
+        ui.add(Label::new(&movie.description).wrap());
+
+        if !movie.extras.is_empty() {
+            ui.add_space(5.0);
+
+            CollapsingHeader::new(t!("movie-other-fields", count = movie.extras.len()))
+                .default_open(true)
+                .show(ui, |ui| {
+                    extras_grid(ui, &movie.extras);
+                });
+        }
+    });
+}
@@ -0,0 +1,476 @@
+use std::ops::Range;
+
+use crate::{
+    communication_layer::{DataLayer, PosterCache},
+    data_types::{Movie, MovieTableSettings, SortColumn},
+    search::{Field, MovieIndex, Query},
+    t,
+    ui::{
+        accessibility::{describe_row, set_label},
+        movie_details::poster_image,
+    },
+};
+
+use egui::{
+    Button, Label, RichText, Sense, TextEdit, TextFormat, TextStyle, Ui, Vec2, text::LayoutJob,
+};
+use egui_extras::{Column, TableBuilder};
+
+const FOOTER_HEIGHT: f32 = 50.0;
+const THUMBNAIL_SIZE: Vec2 = Vec2::new(28.0, 40.0);
+
+pub enum MovieTableAction {
+    SaveSearch(String),
+    DeleteSearch(String),
+}
+
+#[derive(PartialEq)]
+struct RowsKey {
+    revision: u64,
+    query: String,
+    sort_column: Option<SortColumn>,
+    sort_ascending: bool,
+}
+
+pub struct MovieTable {
+    pub settings: MovieTableSettings,
+    query_text: String,
+    query: Option<Query>,
+    query_error: Option<String>,
+    index: MovieIndex,
+    indexed_revision: Option<u64>,
+    show_posters: bool,
+    rows: Vec<usize>,
+    rows_key: Option<RowsKey>,
+    scroll_to_row: Option<usize>,
+    focus_selected: bool,
+    focus_search: bool,
+}
+
+impl MovieTable {
+    pub fn new(settings: MovieTableSettings) -> Self {
+        Self {
+            settings,
+            query_text: String::new(),
+            query: None,
+            query_error: None,
+            index: MovieIndex::new(),
+            indexed_revision: None,
+            show_posters: false,
+            rows: Vec::new(),
+            rows_key: None,
+            scroll_to_row: None,
+            focus_selected: false,
+            focus_search: false,
+        }
+    }
+
+    pub fn focus_search(&mut self) {
+        self.focus_search = true;
+    }
+
+    pub fn move_selection(
+        &mut self,
+        movies: &[Movie],
+        selected_movie_id: &mut Option<i32>,
+        offset: isize,
+    ) {
+        if self.rows.is_empty() {
+            return;
+        }
+
+        let current = selected_movie_id.and_then(|id| {
+            self.rows
+                .iter()
+                .position(|&index| movies.get(index).is_some_and(|movie| movie.id == id))
+        });
+
+        let row = match current {
+            Some(row) => row.saturating_add_signed(offset).min(self.rows.len() - 1),
+            None if offset < 0 => self.rows.len() - 1,
+            None => 0,
+        };
+
+        if let Some(movie) = movies.get(self.rows[row]) {
+            *selected_movie_id = Some(movie.id);
+            self.scroll_to_row = Some(row);
+            self.focus_selected = true;
+        }
+    }
+
+    fn set_query(&mut self, text: String) {
+        match Query::parse(&text) {
+            Ok(query) => {
+                self.query = (!query.is_empty()).then_some(query);
+                self.query_error = None;
+            }
+            Err(e) => self.query_error = Some(e.to_string()),
+        }
+
+        self.query_text = text;
+    }
+
+    fn update_rows(&mut self, movies: &[Movie], revision: u64) {
+        if self.indexed_revision != Some(revision) {
+            self.index.rebuild(movies);
+            self.show_posters = movies.iter().any(|movie| movie.poster_url.is_some());
+            self.indexed_revision = Some(revision);
+        }
+
+        let key = RowsKey {
+            revision,
+            query: self.query_text.clone(),
+            sort_column: self.settings.sort_column,
+            sort_ascending: self.settings.sort_ascending,
+        };
+
+        if self.rows_key.as_ref() == Some(&key) {
+            return;
+        }
+
+        self.rows = match &self.query {
+            Some(query) => {
+                let mut hits = self.index.search(query);
+
+                if query.ranked() && self.settings.sort_column.is_none() {
+                    hits.sort_by(|a, b| b.score.cmp(&a.score).then(a.index.cmp(&b.index)));
+                }
+
+                hits.into_iter().map(|hit| hit.index).collect()
+            }
+            None => (0..movies.len()).collect(),
+        };
+
+        if let Some(column) = self.settings.sort_column {
+            let ascending = self.settings.sort_ascending;
+
+            self.rows.sort_by(|&a, &b| {
+                let ordering = self.index.compare(a, b, column);
+
+                if ascending {
+                    ordering
+                } else {
+                    ordering.reverse()
+                }
+            });
+        }
+
+        self.rows_key = Some(key);
+    }
+
+    fn cell(&self, ui: &mut Ui, field: Field, text: &str) {
+        let ranges = match &self.query {
+            Some(query) => query.highlights(field, text),
+            None => Vec::new(),
+        };
+
+        if ranges.is_empty() {
+            ui.label(text);
+        } else {
+            ui.add(Label::new(highlighted(ui, text, &ranges)));
+        }
+    }
+
+    pub fn show(
+        &mut self,
+        ui: &mut Ui,
+        data_layer: &DataLayer,
+        posters: &mut PosterCache,
+        selected_movie_id: &mut Option<i32>,
+    ) -> Option<MovieTableAction> {
+        let movies = &data_layer.movies;
+        let saved_searches = &data_layer.config.saved_searches;
+        let mut action = None;
+
+        ui.horizontal(|ui| {
+            let mut text = self.query_text.clone();
+
+            let response = ui
+                .add(
+                    TextEdit::singleline(&mut text)
+                        .hint_text(t!("search-hint"))
+                        .desired_width(300.0),
+                )
+                .on_hover_text(t!("search-help"));
+            set_label(&response, t!("a11y-search-movies"));
+
+            if std::mem::take(&mut self.focus_search) {
+                response.request_focus();
+            }
+
+            if response.changed() {
+                self.set_query(text);
+            }
+
+            if ui
+                .add_enabled(!self.query_text.is_empty(), Button::new(t!("search-clear")))
+                .clicked()
+            {
+                self.set_query(String::new());
+            }
+
+            if ui
+                .add_enabled(
+                    self.query.is_some() && self.query_error.is_none(),
+                    Button::new(t!("save-search-title")),
+                )
+                .clicked()
+            {
+                action = Some(MovieTableAction::SaveSearch(self.query_text.clone()));
+            }
+
+            ui.add_enabled_ui(!saved_searches.is_empty(), |ui| {
+                ui.menu_button(t!("search-saved"), |ui| {
+                    for search in saved_searches {
+                        ui.horizontal(|ui| {
+                            if ui
+                                .button(&search.name)
+                                .on_hover_text(&search.query)
+                                .clicked()
+                            {
+                                ui.close_menu();
+
+                                self.set_query(search.query.clone());
+                            }
+
+                            let response = ui.small_button("x").on_hover_text(t!("search-delete"));
+                            set_label(
+                                &response,
+                                t!("a11y-delete-search", name = search.name.as_str()),
+                            );
+
+                            if response.clicked() {
+                                action = Some(MovieTableAction::DeleteSearch(search.name.clone()));
+                            }
+                        });
+                    }
+                });
+            });
+        });
+
+        if let Some(error) = &self.query_error {
+            ui.label(RichText::new(error).color(ui.visuals().error_fg_color));
+        }
+
+        ui.add_space(5.0);
+
+        self.update_rows(movies, data_layer.movies_revision());
+
+        let scroll_height = ui.available_height() - FOOTER_HEIGHT;
+        let mut settings = self.settings.clone();
+
+        let show_posters = self.show_posters;
+        let row_height = if show_posters {
+            THUMBNAIL_SIZE.y + 4.0
+        } else {
+            10.0
+        };
+
+        let mut table = TableBuilder::new(ui).animate_scrolling(true).striped(true);
+
+        if let Some(row) = self.scroll_to_row.take() {
+            table = table.scroll_to_row(row, None);
+        }
+
+        if show_posters {
+            table = table.column(Column::exact(THUMBNAIL_SIZE.x));
+        }
+
+        table
+            .column(
+                Column::initial(settings.title_width)
+                    .at_least(80.0)
+                    .clip(true),
+            )
+            .column(
+                Column::initial(settings.description_width)
+                    .at_least(100.0)
+                    .clip(true),
+            )
+            .column(Column::remainder().at_least(80.0))
+            .sense(Sense::click())
+            .auto_shrink(false)
+            .resizable(true)
+            .max_scroll_height(scroll_height)
+            .header(20.0, |mut header| {
+                if show_posters {
+                    header.col(|_| {});
+                }
+
+                let (_, response) = header.col(|ui| {
+                    sort_header(
+                        ui,
+                        &t!("movie-column-title"),
+                        SortColumn::Title,
+                        &mut settings,
+                    );
+                });
+                settings.title_width = response.rect.width();
+
+                let (_, response) = header.col(|ui| {
+                    sort_header(
+                        ui,
+                        &t!("movie-column-description"),
+                        SortColumn::Description,
+                        &mut settings,
+                    );
+                });
+                settings.description_width = response.rect.width();
+
+                header.col(|ui| {
+                    sort_header(
+                        ui,
+                        &t!("movie-release-year"),
+                        SortColumn::ReleaseYear,
+                        &mut settings,
+                    );
+                });
+            })
+            .body(|body| {
+                body.rows(row_height, self.rows.len(), |mut row| {
+                    let movie = &movies[self.rows[row.index()]];
+
+                    let selected = *selected_movie_id == Some(movie.id);
+                    row.set_selected(selected);
+
+                    if show_posters {
+                        row.col(|ui| {
+                            if let Some(poster_url) = &movie.poster_url {
+                                poster_image(
+                                    ui,
+                                    posters.get(data_layer, poster_url),
+                                    THUMBNAIL_SIZE,
+                                    &movie.title,
+                                );
+                            }
+                        });
+                    }
+
+                    row.col(|ui| {
+                        self.cell(ui, Field::Title, &movie.title);
+                    });
+                    row.col(|ui| {
+                        self.cell(ui, Field::Description, &movie.description);
+                    });
+                    row.col(|ui| {
+                        ui.label(movie.release_year.to_string());
+                    });
+
+                    let response = row.response();
+                    let year = movie.release_year.to_string();
+                    describe_row(
+                        &response,
+                        &[&movie.title, &movie.description, &year],
+                        selected,
+                    );
+
+                    if selected && std::mem::take(&mut self.focus_selected) {
+                        response.request_focus();
+                    }
+
+                    if response.clicked() {
+                        *selected_movie_id = Some(movie.id);
+                    }
+                });
+            });
+
+        self.settings = settings;
+
+        ui.add_space(5.0);
+
+        if self.rows.len() == movies.len() {
+            ui.label(t!("movie-count", count = movies.len()));
+        } else {
+            ui.label(t!(
+                "movie-count-filtered",
+                shown = self.rows.len(),
+                count = movies.len()
+            ));
+        }
+
+        action
+    }
+}
+
+fn highlighted(ui: &Ui, text: &str, ranges: &[Range<usize>]) -> LayoutJob {
+    let plain = TextFormat {
+        font_id: TextStyle::Body.resolve(ui.style()),
+        color: ui.visuals().text_color(),
+        ..Default::default()
+    };
+
+    let highlight = TextFormat {
+        background: ui.visuals().warn_fg_color.gamma_multiply(0.35),
+        color: ui.visuals().strong_text_color(),
+        ..plain.clone()
+    };
+
+    let mut job = LayoutJob::default();
+    let mut segment = String::new();
+    let mut segment_highlighted = false;
+
+    for (position, c) in text.chars().enumerate() {
+        let is_highlighted = ranges.iter().any(|range| range.contains(&position));
+
+        if is_highlighted != segment_highlighted && !segment.is_empty() {
+            let format = if segment_highlighted {
+                highlight.clone()
+            } else {
+                plain.clone()
+            };
+
+            job.append(&std::mem::take(&mut segment), 0.0, format);
+        }
+
+        segment_highlighted = is_highlighted;
+        segment.push(c);
+    }
+
+    let format = if segment_highlighted {
+        highlight
+    } else {
+        plain
+    };
+    job.append(&segment, 0.0, format);
+
+    job
+}
+
+fn sort_header(ui: &mut Ui, label: &str, column: SortColumn, settings: &mut MovieTableSettings) {
+    let text = match settings.sort_column {
+        Some(sorted) if sorted == column && settings.sort_ascending => format!("{} ⏶", label),
+        Some(sorted) if sorted == column => format!("{} ⏷", label),
+        _ => label.to_string(),
+    };
+
+    let response = ui
+        .add(Button::new(text).frame(false))
+        .on_hover_text(t!("movie-sort-hint"));
+
+    set_label(
+        &response,
+        match settings.sort_column {
+            Some(sorted) if sorted == column && settings.sort_ascending => {
+                t!("a11y-sorted-ascending", column = label)
+            }
+            Some(sorted) if sorted == column => t!("a11y-sorted-descending", column = label),
+            _ => label.to_string(),
+        },
+    );
+
+    if response.clicked() {
+        match settings.sort_column {
+            Some(sorted) if sorted == column && settings.sort_ascending => {
+                settings.sort_ascending = false;
+            }
+            Some(sorted) if sorted == column => {
+                settings.sort_column = None;
+                settings.sort_ascending = true;
+            }
+            _ => {
+                settings.sort_column = Some(column);
+                settings.sort_ascending = true;
+            }
+        }
+    }
+}
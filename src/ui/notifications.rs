@@ -0,0 +1,329 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Error;
+use chrono::{DateTime, Local};
+use egui::{
+    Align, Align2, Area, CollapsingHeader, Color32, Context, Frame, Id, Layout, Order, ScrollArea,
+    Stroke, TextEdit, Ui, Window,
+};
+use reqwest::StatusCode;
+
+use crate::{
+    communication_layer::ApiError,
+    data_types::Movie,
+    t,
+    ui::accessibility::{announce, set_label},
+};
+
+const MAX_HISTORY: usize = 100;
+const MAX_TOASTS: usize = 5;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Severity {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub fn name(&self) -> String {
+        match self {
+            Severity::Info => t!("severity-info"),
+            Severity::Success => t!("severity-success"),
+            Severity::Warning => t!("severity-warning"),
+            Severity::Error => t!("severity-error"),
+        }
+    }
+
+    fn color(&self, ui: &Ui) -> Color32 {
+        match self {
+            Severity::Info => Color32::from_rgb(70, 130, 200),
+            Severity::Success => Color32::from_rgb(40, 160, 80),
+            Severity::Warning => ui.visuals().warn_fg_color,
+            Severity::Error => ui.visuals().error_fg_color,
+        }
+    }
+
+    fn toast_duration(&self) -> Duration {
+        match self {
+            Severity::Info | Severity::Success => Duration::from_secs(4),
+            Severity::Warning => Duration::from_secs(8),
+            Severity::Error => Duration::from_secs(12),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub enum NotificationAction {
+    LogIn,
+    ListMovies,
+    ListUsers,
+    UndoDelete(Movie),
+}
+
+impl NotificationAction {
+    pub fn label(&self) -> String {
+        match self {
+            NotificationAction::LogIn => t!("action-log-in-again"),
+            NotificationAction::ListMovies | NotificationAction::ListUsers => t!("action-retry"),
+            NotificationAction::UndoDelete(_) => t!("action-undo"),
+        }
+    }
+}
+
+pub struct Notification {
+    id: u64,
+    pub severity: Severity,
+    pub message: String,
+    pub details: String,
+    pub timestamp: DateTime<Local>,
+    pub action: Option<NotificationAction>,
+    action_until: Option<Instant>,
+    shown_since: Instant,
+    toast_dismissed: bool,
+}
+
+impl Notification {
+    pub fn with_action(&mut self, action: NotificationAction) -> &mut Self {
+        self.action.get_or_insert(action);
+        self
+    }
+
+    pub fn with_timed_action(&mut self, action: NotificationAction, window: Duration) -> &mut Self {
+        self.action = Some(action);
+        self.action_until = Some(Instant::now() + window);
+        self
+    }
+
+    fn action_available(&self, now: Instant) -> bool {
+        self.action.is_some() && self.action_until.is_none_or(|until| now < until)
+    }
+
+    fn toast_visible(&self, now: Instant) -> bool {
+        !self.toast_dismissed
+            && (now.duration_since(self.shown_since) < self.severity.toast_duration()
+                || self.action_until.is_some_and(|until| now < until))
+    }
+
+    fn show(&mut self, ui: &mut Ui, format: &str) -> Option<NotificationAction> {
+        let mut action = None;
+
+        ui.horizontal(|ui| {
+            ui.colored_label(self.severity.color(ui), self.severity.name());
+            ui.weak(self.timestamp.format(format).to_string());
+        });
+
+        let response = ui.label(&self.message);
+        set_label(
+            &response,
+            t!(
+                "a11y-notification",
+                severity = self.severity.name(),
+                message = self.message.as_str()
+            ),
+        );
+        announce(&response, self.severity == Severity::Error);
+
+        if !self.details.is_empty() {
+            CollapsingHeader::new(t!("notifications-details"))
+                .id_salt(("notification_details", self.id))
+                .show(ui, |ui| {
+                    ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                        ui.add(
+                            TextEdit::multiline(&mut self.details.as_str())
+                                .code_editor()
+                                .desired_width(f32::INFINITY),
+                        );
+                    });
+                });
+        }
+
+        if self.action_available(Instant::now())
+            && let Some(notification_action) = &self.action
+            && ui.button(notification_action.label()).clicked()
+        {
+            action = Some(notification_action.clone());
+
+            if self.action_until.is_some() {
+                self.action = None;
+            }
+        }
+
+        action
+    }
+}
+
+pub struct NotificationCenter {
+    pub history_open: bool,
+    notifications: Vec<Notification>,
+    next_id: u64,
+    unread: usize,
+}
+
+impl NotificationCenter {
+    pub fn new() -> Self {
+        Self {
+            history_open: false,
+            notifications: Vec::new(),
+            next_id: 0,
+            unread: 0,
+        }
+    }
+
+    pub fn push(&mut self, severity: Severity, message: impl Into<String>) -> &mut Notification {
+        if self.notifications.len() >= MAX_HISTORY {
+            self.notifications.remove(0);
+        }
+
+        self.next_id += 1;
+        self.unread += 1;
+
+        self.notifications.push(Notification {
+            id: self.next_id,
+            severity,
+            message: message.into(),
+            details: String::new(),
+            timestamp: Local::now(),
+            action: None,
+            action_until: None,
+            shown_since: Instant::now(),
+            toast_dismissed: false,
+        });
+
+        self.notifications.last_mut().unwrap()
+    }
+
+    pub fn info(&mut self, message: impl Into<String>) -> &mut Notification {
+        self.push(Severity::Info, message)
+    }
+
+    pub fn success(&mut self, message: impl Into<String>) -> &mut Notification {
+        self.push(Severity::Success, message)
+    }
+
+    pub fn warning(&mut self, message: impl Into<String>) -> &mut Notification {
+        self.push(Severity::Warning, message)
+    }
+
+    pub fn error(&mut self, context: &str, error: &Error) -> &mut Notification {
+        match error.downcast_ref::<ApiError>() {
+            Some(api_error) => {
+                let unauthorized = api_error.status == StatusCode::UNAUTHORIZED;
+
+                let notification = self.push(
+                    Severity::Error,
+                    format!("{}: {}", context, api_error.explanation()),
+                );
+                notification.details = api_error.body.clone();
+
+                if unauthorized {
+                    notification.with_action(NotificationAction::LogIn);
+                }
+
+                notification
+            }
+            None => self.push(Severity::Error, format!("{}: {}", context, error)),
+        }
+    }
+
+    pub fn unread(&self) -> usize {
+        self.unread
+    }
+
+    pub fn show_toasts(&mut self, ctx: &Context) -> Option<NotificationAction> {
+        let now = Instant::now();
+        let mut action = None;
+        let mut offset = 35.0;
+
+        for notification in self
+            .notifications
+            .iter_mut()
+            .rev()
+            .filter(|notification| notification.toast_visible(now))
+            .take(MAX_TOASTS)
+        {
+            let response = Area::new(Id::new(("toast", notification.id)))
+                .order(Order::Foreground)
+                .anchor(Align2::RIGHT_BOTTOM, [-10.0, -offset])
+                .show(ctx, |ui| {
+                    Frame::popup(ui.style())
+                        .stroke(Stroke::new(1.5, notification.severity.color(ui)))
+                        .show(ui, |ui| {
+                            ui.set_max_width(300.0);
+
+                            ui.with_layout(Layout::right_to_left(Align::TOP), |ui| {
+                                let response = ui.small_button("x");
+                                set_label(&response, t!("a11y-dismiss"));
+
+                                if response.clicked() {
+                                    notification.toast_dismissed = true;
+                                }
+
+                                ui.with_layout(Layout::top_down(Align::LEFT), |ui| {
+                                    if let Some(clicked) = notification.show(ui, "%H:%M:%S") {
+                                        notification.toast_dismissed = true;
+                                        action = Some(clicked);
+                                    }
+                                });
+                            });
+                        });
+                })
+                .response;
+
+            if response.contains_pointer() {
+                notification.shown_since = now;
+            }
+
+            offset += response.rect.height() + 5.0;
+        }
+
+        if self
+            .notifications
+            .iter()
+            .any(|notification| notification.toast_visible(now))
+        {
+            ctx.request_repaint_after(Duration::from_millis(250));
+        }
+
+        action
+    }
+
+    pub fn show_history(&mut self, ctx: &Context) -> Option<NotificationAction> {
+        let mut action = None;
+        let mut open = self.history_open;
+
+        self.unread = 0;
+
+        Window::new(t!("notifications-title"))
+            .open(&mut open)
+            .default_size([400.0, 300.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(t!("notifications-count", count = self.notifications.len()));
+
+                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                        if ui.button(t!("notifications-clear")).clicked() {
+                            self.notifications.clear();
+                        }
+                    });
+                });
+
+                ui.separator();
+
+                ScrollArea::vertical().auto_shrink(false).show(ui, |ui| {
+                    for notification in self.notifications.iter_mut().rev() {
+                        if let Some(clicked) = notification.show(ui, "%Y-%m-%d %H:%M:%S") {
+                            action = Some(clicked);
+                        }
+
+                        ui.separator();
+                    }
+                });
+            });
+
+        self.history_open = open;
+
+        action
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{data_types::PasswordPolicy, t};
+
+use egui::{Color32, Event, ProgressBar, Response, TextEdit, Ui};
+
+#[derive(Clone, Copy, Default)]
+pub struct PasswordInput {
+    visible: bool,
+    caps_lock: bool,
+}
+
+impl PasswordInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn show(&mut self, ui: &mut Ui, password: &mut String) -> Response {
+        let response = ui
+            .horizontal(|ui| {
+                let response = ui.add(TextEdit::singleline(password).password(!self.visible));
+
+                let toggle = if self.visible {
+                    t!("password-hide")
+                } else {
+                    t!("password-show")
+                };
+
+                if ui.small_button(toggle).clicked() {
+                    self.visible = !self.visible;
+                }
+
+                response
+            })
+            .inner;
+
+        if response.has_focus() {
+            self.update_caps_lock(ui);
+
+            if self.caps_lock {
+                ui.colored_label(ui.visuals().warn_fg_color, t!("password-caps-lock"));
+            }
+        }
+
+        response
+    }
+
+    fn update_caps_lock(&mut self, ui: &Ui) {
+        ui.input(|input| {
+            for event in input.events.iter() {
+                if let Event::Text(text) = event
+                    && let Some(c) = text.chars().rev().find(|c| c.is_alphabetic())
+                    && c.is_lowercase() != c.is_uppercase()
+                {
+                    self.caps_lock = c.is_uppercase() != input.modifiers.shift;
+                }
+            }
+        });
+    }
+}
+
+pub fn strength_meter(ui: &mut Ui, policy: &PasswordPolicy, password: &str, username: &str) {
+    let strength = PasswordPolicy::strength(password);
+
+    let (text, color) = match strength {
+        0 | 1 => (t!("password-weak"), Color32::from_rgb(200, 60, 60)),
+        2 => (t!("password-fair"), Color32::from_rgb(220, 160, 40)),
+        3 => (t!("password-good"), Color32::from_rgb(120, 180, 60)),
+        _ => (t!("password-strong"), Color32::from_rgb(40, 160, 80)),
+    };
+
+    ui.add(
+        ProgressBar::new(strength as f32 / 4.0)
+            .fill(color)
+            .text(text)
+            .desired_width(200.0),
+    );
+
+    for violation in policy.violations(password, username) {
+        ui.colored_label(ui.visuals().error_fg_color, violation);
+    }
+}
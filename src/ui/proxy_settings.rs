@@ -0,0 +1,147 @@
+use std::time::Duration;
+
+use crate::{
+    communication_layer::ConnectionTest,
+    config::Config,
+    data_types::ProxySettings,
+    t,
+    ui::{
+        accessibility::initial_focus,
+        dialog::{Dialog, DialogOutcome},
+    },
+};
+
+use egui::{Align, Button, Layout, TextEdit, Ui};
+
+pub struct ProxySettingsDialog {
+    config: Config,
+    client_error: Option<String>,
+    connection_test: Option<ConnectionTest>,
+    test_result: String,
+}
+
+impl Clone for ProxySettingsDialog {
+    fn clone(&self) -> Self {
+        Self {
+            config: self.config.clone(),
+            client_error: self.client_error.clone(),
+            connection_test: None,
+            test_result: self.test_result.clone(),
+        }
+    }
+}
+
+impl ProxySettingsDialog {
+    pub fn new(config: Config, client_error: Option<String>) -> Self {
+        Self {
+            config,
+            client_error,
+            connection_test: None,
+            test_result: String::new(),
+        }
+    }
+}
+
+impl Dialog for ProxySettingsDialog {
+    type Output = ProxySettings;
+
+    fn title(&self) -> String {
+        t!("proxy-title")
+    }
+
+    fn show(&mut self, ui: &mut Ui) -> Option<DialogOutcome<ProxySettings>> {
+        let mut outcome = None;
+
+        ui.label(t!("proxy-intro"));
+
+        if let Some(error) = &self.client_error {
+            ui.colored_label(ui.visuals().error_fg_color, error);
+        }
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("proxy-http"));
+            let response = ui
+                .text_edit_singleline(&mut self.config.proxy.http_url)
+                .labelled_by(label.id);
+            initial_focus(&response);
+        });
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("proxy-https"));
+            ui.text_edit_singleline(&mut self.config.proxy.https_url)
+                .labelled_by(label.id);
+        });
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("proxy-socks5"));
+            ui.text_edit_singleline(&mut self.config.proxy.socks5_url)
+                .labelled_by(label.id);
+        });
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("label-username"));
+            ui.text_edit_singleline(&mut self.config.proxy.username)
+                .labelled_by(label.id);
+        });
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("label-password"));
+            ui.add(TextEdit::singleline(&mut self.config.proxy.password).password(true))
+                .labelled_by(label.id);
+        });
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("proxy-no-proxy"));
+            ui.text_edit_singleline(&mut self.config.proxy.no_proxy)
+                .on_hover_text(t!("proxy-no-proxy-hint"))
+                .labelled_by(label.id);
+        });
+
+        if let Some(result) = self.connection_test.as_ref().and_then(ConnectionTest::poll) {
+            self.connection_test = None;
+            self.test_result = match result {
+                Ok(status) => t!("proxy-connected", status = status.to_string()),
+                Err(e) => t!("proxy-connection-failed", error = e.to_string()),
+            };
+        }
+
+        if self.connection_test.is_some() {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(t!("proxy-testing"));
+            });
+
+            ui.ctx().request_repaint_after(Duration::from_millis(100));
+        } else if !self.test_result.is_empty() {
+            ui.label(&self.test_result);
+        }
+
+        ui.horizontal(|ui| {
+            ui.with_layout(Layout::right_to_left(Align::RIGHT), |ui| {
+                if ui.button(t!("button-save")).clicked() {
+                    outcome = self.submit().map(DialogOutcome::Submitted);
+                }
+
+                if ui.button(t!("button-cancel")).clicked() {
+                    outcome = Some(DialogOutcome::Cancelled);
+                }
+
+                if ui
+                    .add_enabled(
+                        self.connection_test.is_none(),
+                        Button::new(t!("proxy-test-connection")),
+                    )
+                    .clicked()
+                {
+                    self.connection_test = Some(ConnectionTest::start(self.config.clone()));
+                }
+            });
+        });
+
+        outcome
+    }
+
+    fn submit(&mut self) -> Option<ProxySettings> {
+        Some(self.config.proxy.clone())
+    }
+}
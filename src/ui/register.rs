@@ -1,14 +1,35 @@
-use crate::ui::dialog::Dialog;
+use crate::{
+    data_types::{FieldErrors, PasswordPolicy, Role},
+    t,
+    ui::{
+        accessibility::initial_focus,
+        dialog::{Dialog, DialogOutcome},
+        password::{PasswordInput, strength_meter},
+        validation::{field_error, mark_field},
+    },
+};
 
-use egui::{Align, ComboBox, Context, Layout, Window};
+use egui::{Align, ComboBox, Layout, Ui};
 
-use std::any::Any;
+pub fn role_name(role: &str) -> String {
+    if role.is_empty() {
+        return String::new();
+    }
+
+    Role::from(role.to_string()).name()
+}
 
 pub struct RegisterDialog {
     username: String,
     password: String,
+    confirm_password: String,
     role: String,
-    changed: bool,
+    allow_admin: bool,
+    policy: PasswordPolicy,
+    password_input: PasswordInput,
+    confirm_input: PasswordInput,
+    errors: FieldErrors,
+    submitted: bool,
 }
 
 impl Clone for RegisterDialog {
@@ -16,81 +37,184 @@ impl Clone for RegisterDialog {
         Self {
             username: self.username.clone(),
             password: self.password.clone(),
+            confirm_password: self.confirm_password.clone(),
             role: self.role.clone(),
-            changed: self.changed,
+            allow_admin: self.allow_admin,
+            policy: self.policy.clone(),
+            password_input: self.password_input,
+            confirm_input: self.confirm_input,
+            errors: self.errors.clone(),
+            submitted: self.submitted,
         }
     }
 }
 
 impl RegisterDialog {
-    pub fn new() -> Self {
+    pub fn new(allow_admin: bool, policy: PasswordPolicy) -> Self {
         Self {
             username: String::new(),
             password: String::new(),
+            confirm_password: String::new(),
             role: "".to_string(),
-            changed: false,
+            allow_admin,
+            policy,
+            password_input: PasswordInput::new(),
+            confirm_input: PasswordInput::new(),
+            errors: FieldErrors::new(),
+            submitted: false,
         }
     }
 
-    pub fn get_data(&self) -> Option<(String, String, String)> {
-        if self.valid() {
-            Some((
-                self.username.clone(),
-                self.password.clone(),
-                self.role.clone(),
-            ))
-        } else {
-            None
+    fn validate(&self) -> FieldErrors {
+        let mut errors = FieldErrors::new();
+
+        errors.require(
+            "username",
+            !self.username.is_empty(),
+            t!("validation-username-required"),
+        );
+        errors.require(
+            "password",
+            !self.password.is_empty(),
+            t!("validation-password-required"),
+        );
+
+        if let Some(violation) = self
+            .policy
+            .violations(&self.password, &self.username)
+            .into_iter()
+            .next()
+        {
+            errors.add("password", violation);
         }
-    }
 
-    fn valid(&self) -> bool {
-        !self.username.is_empty() && !self.password.is_empty() && !self.role.is_empty()
+        errors.require(
+            "confirm_password",
+            self.password == self.confirm_password,
+            t!("validation-passwords-mismatch"),
+        );
+        errors.require(
+            "role",
+            !self.role.is_empty(),
+            t!("validation-role-required"),
+        );
+        errors.require(
+            "role",
+            self.allow_admin || self.role != "Admin",
+            t!("register-admin-only"),
+        );
+
+        errors
     }
 }
 
 impl Dialog for RegisterDialog {
-    fn show(&mut self, ctx: &Context, open: &mut bool) {
-        Window::new("Register").open(open).show(ctx, |ui| {
-            ui.label("Register a new user");
+    type Output = (String, String, String);
 
-            ui.horizontal(|ui| {
-                ui.label("Username:");
-                ui.text_edit_singleline(&mut self.username).highlight();
-            });
+    fn title(&self) -> String {
+        t!("register-title")
+    }
 
-            ui.horizontal(|ui| {
-                ui.label("Password:");
-                ui.text_edit_singleline(&mut self.password).highlight();
-            });
+    fn show(&mut self, ui: &mut Ui) -> Option<DialogOutcome<Self::Output>> {
+        let mut outcome = None;
+
+        ui.label(t!("register-intro"));
+
+        let mut edited = false;
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("label-username"));
+            let response = ui
+                .text_edit_singleline(&mut self.username)
+                .highlight()
+                .labelled_by(label.id);
+            mark_field(ui, &response, &self.errors, "username");
+            initial_focus(&response);
+            edited |= response.changed();
+        });
+        field_error(ui, &self.errors, "username");
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("label-password"));
+            let response = self
+                .password_input
+                .show(ui, &mut self.password)
+                .labelled_by(label.id);
+            mark_field(ui, &response, &self.errors, "password");
+            edited |= response.changed();
+        });
+
+        strength_meter(ui, &self.policy, &self.password, &self.username);
+        field_error(ui, &self.errors, "password");
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("label-confirm-password"));
+            let response = self
+                .confirm_input
+                .show(ui, &mut self.confirm_password)
+                .labelled_by(label.id);
+            mark_field(ui, &response, &self.errors, "confirm_password");
+            edited |= response.changed();
+        });
+
+        field_error(ui, &self.errors, "confirm_password");
 
-            ui.horizontal(|ui| {
-                ui.label("Role:");
-                ComboBox::from_label("")
-                    .selected_text(&self.role)
-                    .show_ui(ui, |ui| {
-                        ui.selectable_value(&mut self.role, "Admin".to_string(), "Admin");
-                        ui.selectable_value(&mut self.role, "User".to_string(), "User");
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("label-role"));
+            let response = ComboBox::from_id_salt("role")
+                .selected_text(role_name(&self.role))
+                .show_ui(ui, |ui| {
+                    ui.add_enabled_ui(self.allow_admin, |ui| {
+                        ui.selectable_value(&mut self.role, "Admin".to_string(), t!("role-admin"))
+                            .on_disabled_hover_text(t!("register-admin-only"));
                     });
-            });
+                    ui.selectable_value(&mut self.role, "User".to_string(), t!("role-user"));
+                })
+                .response
+                .labelled_by(label.id);
+            mark_field(ui, &response, &self.errors, "role");
+        });
+        field_error(ui, &self.errors, "role");
+
+        if edited && self.submitted {
+            self.errors = self.validate();
+        }
 
-            ui.horizontal(|ui| {
-                ui.with_layout(Layout::right_to_left(Align::RIGHT), |ui| {
-                    if ui.button("Register").clicked() {
-                        if self.valid() {
-                            self.changed = true;
-                        }
-                    }
-                });
+        ui.horizontal(|ui| {
+            ui.with_layout(Layout::right_to_left(Align::RIGHT), |ui| {
+                if ui.button(t!("register-button")).clicked() {
+                    outcome = self.submit().map(DialogOutcome::Submitted);
+                }
+
+                if ui.button(t!("button-cancel")).clicked() {
+                    outcome = Some(DialogOutcome::Cancelled);
+                }
             });
         });
+
+        outcome
     }
 
-    fn changed(&self) -> (bool, &str) {
-        (self.changed, "Register")
+    fn submit(&mut self) -> Option<Self::Output> {
+        self.submitted = true;
+        self.errors = self.validate();
+
+        self.errors.is_empty().then(|| {
+            (
+                self.username.clone(),
+                self.password.clone(),
+                self.role.clone(),
+            )
+        })
     }
 
-    fn as_any(&self) -> &dyn Any {
-        self
+    fn apply_field_errors(&mut self, errors: &FieldErrors) -> bool {
+        if !errors.any_of(&["username", "password", "role"]) {
+            return false;
+        }
+
+        self.errors = errors.clone();
+
+        true
     }
 }
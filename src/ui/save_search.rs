@@ -0,0 +1,96 @@
+use crate::{
+    data_types::FieldErrors,
+    t,
+    ui::{
+        accessibility::initial_focus,
+        dialog::{Dialog, DialogOutcome},
+        validation::{field_error, mark_field},
+    },
+};
+
+use egui::{Align, Layout, Ui};
+
+pub struct SaveSearchDialog {
+    query: String,
+    name: String,
+    existing: Vec<String>,
+    errors: FieldErrors,
+    submitted: bool,
+}
+
+impl SaveSearchDialog {
+    pub fn new(query: String, existing: Vec<String>) -> Self {
+        Self {
+            query,
+            name: String::new(),
+            existing,
+            errors: FieldErrors::new(),
+            submitted: false,
+        }
+    }
+
+    fn validate(&self) -> FieldErrors {
+        let mut errors = FieldErrors::new();
+
+        errors.require(
+            "name",
+            !self.name.trim().is_empty(),
+            t!("validation-name-required"),
+        );
+
+        errors
+    }
+}
+
+impl Dialog for SaveSearchDialog {
+    type Output = String;
+
+    fn title(&self) -> String {
+        t!("save-search-title")
+    }
+
+    fn show(&mut self, ui: &mut Ui) -> Option<DialogOutcome<String>> {
+        let mut outcome = None;
+
+        ui.label(t!("save-search-query", query = self.query.as_str()));
+
+        ui.horizontal(|ui| {
+            let label = ui.label(t!("save-search-name"));
+            let response = ui
+                .text_edit_singleline(&mut self.name)
+                .labelled_by(label.id);
+            mark_field(ui, &response, &self.errors, "name");
+            initial_focus(&response);
+
+            if response.changed() && self.submitted {
+                self.errors = self.validate();
+            }
+        });
+        field_error(ui, &self.errors, "name");
+
+        if self.existing.contains(&self.name.trim().to_string()) {
+            ui.label(t!("save-search-replace"));
+        }
+
+        ui.horizontal(|ui| {
+            ui.with_layout(Layout::right_to_left(Align::RIGHT), |ui| {
+                if ui.button(t!("button-save")).clicked() {
+                    outcome = self.submit().map(DialogOutcome::Submitted);
+                }
+
+                if ui.button(t!("button-cancel")).clicked() {
+                    outcome = Some(DialogOutcome::Cancelled);
+                }
+            });
+        });
+
+        outcome
+    }
+
+    fn submit(&mut self) -> Option<String> {
+        self.submitted = true;
+        self.errors = self.validate();
+
+        self.errors.is_empty().then(|| self.name.trim().to_string())
+    }
+}
@@ -0,0 +1,208 @@
+use anyhow::{Result, anyhow};
+
+use crate::{
+    data_types::{Command, FieldErrors, KeyBindings},
+    t,
+    ui::{
+        accessibility::{initial_focus, set_label},
+        dialog::{Dialog, DialogOutcome},
+        validation::{field_error, mark_field},
+    },
+};
+
+use egui::{Align, Button, Grid, Key, KeyboardShortcut, Layout, Modifiers, ScrollArea, Ui};
+
+pub fn parse_shortcut(text: &str) -> Result<Option<KeyboardShortcut>> {
+    let text = text.trim();
+
+    if text.is_empty() {
+        return Ok(None);
+    }
+
+    let mut parts: Vec<&str> = text.split('+').map(str::trim).collect();
+    let key = parts.pop().unwrap_or_default();
+
+    let mut modifiers = Modifiers::NONE;
+
+    for part in parts {
+        modifiers |= match part.to_lowercase().as_str() {
+            "ctrl" | "cmd" | "command" => Modifiers::COMMAND,
+            "shift" => Modifiers::SHIFT,
+            "alt" | "option" => Modifiers::ALT,
+            _ => return Err(anyhow!(t!("shortcut-unknown-modifier", modifier = part))),
+        };
+    }
+
+    let key = Key::from_name(key)
+        .or_else(|| Key::from_name(&key.to_uppercase()))
+        .ok_or_else(|| anyhow!(t!("shortcut-unknown-key", key = key)))?;
+
+    Ok(Some(KeyboardShortcut::new(modifiers, key)))
+}
+
+pub fn format_shortcut(shortcut: &KeyboardShortcut) -> String {
+    let mut text = String::new();
+
+    if shortcut.modifiers.command {
+        text.push_str("Ctrl+");
+    }
+
+    if shortcut.modifiers.alt {
+        text.push_str("Alt+");
+    }
+
+    if shortcut.modifiers.shift {
+        text.push_str("Shift+");
+    }
+
+    text.push_str(shortcut.logical_key.name());
+
+    text
+}
+
+pub struct ShortcutsDialog {
+    bindings: Vec<(Command, String)>,
+    errors: FieldErrors,
+    submitted: bool,
+}
+
+impl ShortcutsDialog {
+    pub fn new(bindings: &KeyBindings) -> Self {
+        Self {
+            bindings: Command::ALL
+                .into_iter()
+                .map(|command| (command, bindings.get(command).to_string()))
+                .collect(),
+            errors: FieldErrors::new(),
+            submitted: false,
+        }
+    }
+
+    fn validate(&self) -> FieldErrors {
+        let mut errors = FieldErrors::new();
+        let mut used: Vec<(String, Command)> = Vec::new();
+
+        for (command, text) in self.bindings.iter() {
+            match parse_shortcut(text) {
+                Ok(Some(shortcut)) => {
+                    let shortcut = format_shortcut(&shortcut);
+
+                    if let Some((_, other)) = used.iter().find(|(used, _)| *used == shortcut) {
+                        errors.add(
+                            &command.name(),
+                            t!(
+                                "shortcut-already-used",
+                                shortcut = shortcut.as_str(),
+                                command = other.name()
+                            ),
+                        );
+                    } else {
+                        used.push((shortcut, *command));
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => errors.add(&command.name(), e.to_string()),
+            }
+        }
+
+        errors
+    }
+}
+
+impl Dialog for ShortcutsDialog {
+    type Output = KeyBindings;
+
+    fn title(&self) -> String {
+        t!("command-keyboard-shortcuts")
+    }
+
+    fn show(&mut self, ui: &mut Ui) -> Option<DialogOutcome<KeyBindings>> {
+        let mut outcome = None;
+        let mut edited = false;
+
+        ui.label(t!("shortcuts-hint"));
+
+        ScrollArea::vertical().max_height(350.0).show(ui, |ui| {
+            Grid::new("shortcuts").num_columns(3).show(ui, |ui| {
+                for (index, (command, text)) in self.bindings.iter_mut().enumerate() {
+                    let label = ui.label(command.name());
+
+                    let response = ui.text_edit_singleline(text).labelled_by(label.id);
+                    mark_field(ui, &response, &self.errors, &command.name());
+                    edited |= response.changed();
+
+                    if index == 0 {
+                        initial_focus(&response);
+                    }
+
+                    let response = ui.add_enabled(
+                        text != command.default_shortcut(),
+                        Button::new(t!("button-reset")),
+                    );
+                    set_label(
+                        &response,
+                        t!("a11y-reset-shortcut", command = command.name()),
+                    );
+
+                    if response.clicked() {
+                        *text = command.default_shortcut().to_string();
+                        edited = true;
+                    }
+
+                    ui.end_row();
+
+                    if self.errors.get(&command.name()).is_some() {
+                        ui.label("");
+                        field_error(ui, &self.errors, &command.name());
+                        ui.end_row();
+                    }
+                }
+            });
+        });
+
+        if edited && self.submitted {
+            self.errors = self.validate();
+        }
+
+        ui.horizontal(|ui| {
+            ui.with_layout(Layout::right_to_left(Align::RIGHT), |ui| {
+                if ui.button(t!("button-save")).clicked() {
+                    outcome = self.submit().map(DialogOutcome::Submitted);
+                }
+
+                if ui.button(t!("button-cancel")).clicked() {
+                    outcome = Some(DialogOutcome::Cancelled);
+                }
+            });
+        });
+
+        outcome
+    }
+
+    fn submit(&mut self) -> Option<KeyBindings> {
+        self.submitted = true;
+        self.errors = self.validate();
+
+        if !self.errors.is_empty() {
+            return None;
+        }
+
+        let mut bindings = KeyBindings::default();
+
+        for (command, text) in self.bindings.iter() {
+            let shortcut = parse_shortcut(text)
+                .ok()
+                .flatten()
+                .map(|shortcut| format_shortcut(&shortcut))
+                .unwrap_or_default();
+
+            bindings.set(*command, shortcut);
+        }
+
+        Some(bindings)
+    }
+
+    fn submit_on_enter(&self) -> bool {
+        false
+    }
+}
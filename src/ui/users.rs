@@ -0,0 +1,189 @@
+use crate::{
+    data_types::{Role, User},
+    t,
+    ui::{
+        accessibility::{describe_row, set_label},
+        register::role_name,
+    },
+};
+
+use egui::{Button, ComboBox, Context, Sense, TextEdit, Window};
+use egui_extras::{Column, TableBuilder};
+
+pub enum UserAction {
+    Refresh,
+    ChangeRole(i32, Role),
+    ResetPassword(i32, String),
+    SetDisabled(i32, bool),
+    Delete(i32),
+}
+
+pub struct UserManagementPanel {
+    pub open: bool,
+    selected_user_id: Option<i32>,
+    role: String,
+    new_password: String,
+}
+
+impl UserManagementPanel {
+    pub fn new() -> Self {
+        Self {
+            open: false,
+            selected_user_id: None,
+            role: String::new(),
+            new_password: String::new(),
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context, users: &[User]) -> Option<UserAction> {
+        let mut action = None;
+        let mut open = self.open;
+
+        Window::new(t!("users-title"))
+            .open(&mut open)
+            .default_size([450.0, 300.0])
+            .show(ctx, |ui| {
+                let selected = self
+                    .selected_user_id
+                    .and_then(|id| users.iter().find(|user| user.id == id));
+
+                ui.horizontal(|ui| {
+                    if ui.button(t!("users-refresh")).clicked() {
+                        action = Some(UserAction::Refresh);
+                    }
+
+                    ui.separator();
+
+                    ComboBox::from_label(t!("users-role"))
+                        .selected_text(role_name(&self.role))
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(
+                                &mut self.role,
+                                "Admin".to_string(),
+                                t!("role-admin"),
+                            );
+                            ui.selectable_value(
+                                &mut self.role,
+                                "User".to_string(),
+                                t!("role-user"),
+                            );
+                        });
+
+                    if ui
+                        .add_enabled(
+                            selected.is_some() && !self.role.is_empty(),
+                            Button::new(t!("users-change-role")),
+                        )
+                        .clicked()
+                        && let Some(user) = selected
+                    {
+                        action = Some(UserAction::ChangeRole(
+                            user.id,
+                            Role::from(self.role.clone()),
+                        ));
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    let response = ui.add(
+                        TextEdit::singleline(&mut self.new_password)
+                            .password(true)
+                            .hint_text(t!("users-new-password")),
+                    );
+                    set_label(&response, t!("users-new-password"));
+
+                    if ui
+                        .add_enabled(
+                            selected.is_some() && !self.new_password.is_empty(),
+                            Button::new(t!("users-reset-password")),
+                        )
+                        .clicked()
+                        && let Some(user) = selected
+                    {
+                        action = Some(UserAction::ResetPassword(
+                            user.id,
+                            std::mem::take(&mut self.new_password),
+                        ));
+                    }
+
+                    let disable_label = match selected {
+                        Some(user) if user.disabled => t!("users-enable"),
+                        _ => t!("users-disable"),
+                    };
+
+                    if ui
+                        .add_enabled(selected.is_some(), Button::new(disable_label))
+                        .clicked()
+                        && let Some(user) = selected
+                    {
+                        action = Some(UserAction::SetDisabled(user.id, !user.disabled));
+                    }
+
+                    if ui
+                        .add_enabled(selected.is_some(), Button::new(t!("users-delete")))
+                        .clicked()
+                        && let Some(user) = selected
+                    {
+                        action = Some(UserAction::Delete(user.id));
+                    }
+                });
+
+                ui.add_space(5.0);
+
+                TableBuilder::new(ui)
+                    .striped(true)
+                    .column(Column::initial(150.0).at_least(100.0))
+                    .column(Column::initial(100.0).at_least(80.0))
+                    .column(Column::remainder())
+                    .sense(Sense::click())
+                    .auto_shrink(false)
+                    .header(10.0, |mut header| {
+                        header.col(|ui| {
+                            ui.label(t!("users-column-username"));
+                        });
+                        header.col(|ui| {
+                            ui.label(t!("users-column-role"));
+                        });
+                        header.col(|ui| {
+                            ui.label(t!("users-column-status"));
+                        });
+                    })
+                    .body(|body| {
+                        body.rows(10.0, users.len(), |mut row| {
+                            let user = &users[row.index()];
+
+                            let selected = self.selected_user_id == Some(user.id);
+                            row.set_selected(selected);
+
+                            let role = Role::from(user.role.clone()).name();
+                            let status = if user.disabled {
+                                t!("users-disabled")
+                            } else {
+                                t!("users-active")
+                            };
+
+                            row.col(|ui| {
+                                ui.label(&user.username);
+                            });
+                            row.col(|ui| {
+                                ui.label(&role);
+                            });
+                            row.col(|ui| {
+                                ui.label(&status);
+                            });
+
+                            let response = row.response();
+                            describe_row(&response, &[&user.username, &role, &status], selected);
+
+                            if response.clicked() {
+                                self.selected_user_id = Some(user.id);
+                            }
+                        });
+                    });
+            });
+
+        self.open = open;
+
+        action
+    }
+}
@@ -0,0 +1,25 @@
+use crate::data_types::FieldErrors;
+
+use egui::{Response, Stroke, StrokeKind, Ui, accesskit};
+
+pub fn mark_field(ui: &Ui, response: &Response, errors: &FieldErrors, field: &str) {
+    if let Some(message) = errors.get(field) {
+        ui.painter().rect_stroke(
+            response.rect.expand(1.0),
+            2.0,
+            Stroke::new(1.5, ui.visuals().error_fg_color),
+            StrokeKind::Outside,
+        );
+
+        ui.ctx().accesskit_node_builder(response.id, |node| {
+            node.set_invalid(accesskit::Invalid::True);
+            node.set_description(message);
+        });
+    }
+}
+
+pub fn field_error(ui: &mut Ui, errors: &FieldErrors, field: &str) {
+    if let Some(message) = errors.get(field) {
+        ui.colored_label(ui.visuals().error_fg_color, message);
+    }
+}